@@ -0,0 +1,186 @@
+//! Above-the-line adjustments to income (Schedule 1, Part II), subtracted
+//! from gross income to arrive at AGI.
+//!
+//! Currently covers the IRC § 221 student loan interest deduction; other
+//! Part II adjustments (educator expenses, HSA contributions, etc.) aren't
+//! modeled yet and contribute zero.
+
+use crate::error::ValidationErrors;
+use crate::money::Money;
+use crate::traits::{InputForm, InputFormCollection, TaxRules};
+use crate::types::FilingStatus;
+
+/// Above-the-line adjustments computed from a taxpayer's input forms.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Adjustments {
+    /// Allowed student loan interest deduction (IRC § 221), after the
+    /// per-return cap and MAGI phase-out.
+    pub student_loan_interest_deduction: Money,
+}
+
+impl Adjustments {
+    /// Total of all above-the-line adjustments.
+    pub fn total(&self) -> Money {
+        self.student_loan_interest_deduction
+    }
+
+    /// Computes above-the-line adjustments from `forms`, given the
+    /// taxpayer's filing status, MAGI, and the active `rules`.
+    ///
+    /// Married-filing-separately taxpayers are categorically ineligible for
+    /// the student loan interest deduction under § 221(e)(2); that's
+    /// enforced here rather than in `TaxRules`. Any amount lost to the cap
+    /// or to the MAGI phase-out is surfaced as a `ValidationError::warning`
+    /// so the caller can explain the reduction to the taxpayer.
+    pub fn compute(
+        forms: &[Box<dyn InputForm>],
+        status: FilingStatus,
+        magi: Money,
+        rules: &dyn TaxRules,
+    ) -> (Adjustments, ValidationErrors) {
+        let mut errors = ValidationErrors::new();
+
+        let student_loan_interest_deduction = if status == FilingStatus::MarriedFilingSeparately {
+            Money::ZERO
+        } else {
+            let paid = forms.total_student_loan_interest();
+            let allowed = rules.calculate_student_loan_interest_deduction(status, paid, magi);
+            if allowed < paid {
+                errors.add_warning(
+                    "student_loan_interest_deduction",
+                    format!(
+                        "student loan interest paid {} was reduced to {} by the deduction cap \
+                         and/or MAGI phase-out",
+                        paid.as_decimal(),
+                        allowed.as_decimal()
+                    ),
+                );
+            }
+            allowed
+        };
+
+        (
+            Adjustments {
+                student_loan_interest_deduction,
+            },
+            errors,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Rules2025;
+    use crate::types::{InputFormType, TaxYear};
+
+    #[derive(Debug)]
+    struct StubForm {
+        student_loan_interest: Money,
+    }
+
+    impl InputForm for StubForm {
+        fn form_type(&self) -> InputFormType {
+            InputFormType::F1099Int
+        }
+
+        fn tax_year(&self) -> TaxYear {
+            2025
+        }
+
+        fn form_id(&self) -> &str {
+            "stub-1098e"
+        }
+
+        fn student_loan_interest(&self) -> Option<Money> {
+            Some(self.student_loan_interest)
+        }
+    }
+
+    fn forms_with_interest(amount: Money) -> Vec<Box<dyn InputForm>> {
+        vec![Box::new(StubForm {
+            student_loan_interest: amount,
+        })]
+    }
+
+    #[test]
+    fn test_under_cap_and_below_phase_out_is_fully_allowed() {
+        let rules = Rules2025::new();
+        let forms = forms_with_interest(Money::from_dollars(1_200));
+        let (adjustments, errors) = Adjustments::compute(
+            &forms,
+            FilingStatus::Single,
+            Money::from_dollars(60_000),
+            &rules,
+        );
+        assert_eq!(
+            adjustments.student_loan_interest_deduction,
+            Money::from_dollars(1_200)
+        );
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_interest_above_cap_is_truncated_with_warning() {
+        let rules = Rules2025::new();
+        let forms = forms_with_interest(Money::from_dollars(4_000));
+        let (adjustments, errors) = Adjustments::compute(
+            &forms,
+            FilingStatus::Single,
+            Money::from_dollars(60_000),
+            &rules,
+        );
+        assert_eq!(
+            adjustments.student_loan_interest_deduction,
+            Money::from_dollars(2_500)
+        );
+        assert!(errors.has_warnings());
+    }
+
+    #[test]
+    fn test_high_magi_phases_out_deduction_with_warning() {
+        let rules = Rules2025::new();
+        let forms = forms_with_interest(Money::from_dollars(2_500));
+        let (adjustments, errors) = Adjustments::compute(
+            &forms,
+            FilingStatus::Single,
+            Money::from_dollars(95_000),
+            &rules,
+        );
+        assert_eq!(adjustments.student_loan_interest_deduction, Money::ZERO);
+        assert!(errors.has_warnings());
+    }
+
+    #[test]
+    fn test_phase_out_is_proportional_to_position_in_range_not_flat() {
+        // Single, $80,000 threshold, $15,000 range: $87,500 MAGI is halfway
+        // through the range, so half of the capped interest is allowed.
+        let rules = Rules2025::new();
+        let forms = forms_with_interest(Money::from_dollars(1_000));
+        let (adjustments, errors) = Adjustments::compute(
+            &forms,
+            FilingStatus::Single,
+            Money::from_dollars(87_500),
+            &rules,
+        );
+        assert_eq!(
+            adjustments.student_loan_interest_deduction,
+            Money::from_dollars(500)
+        );
+        assert!(errors.has_warnings());
+    }
+
+    #[test]
+    fn test_married_filing_separately_is_categorically_ineligible() {
+        let rules = Rules2025::new();
+        let forms = forms_with_interest(Money::from_dollars(1_000));
+        let (adjustments, errors) = Adjustments::compute(
+            &forms,
+            FilingStatus::MarriedFilingSeparately,
+            Money::from_dollars(50_000),
+            &rules,
+        );
+        assert_eq!(adjustments.student_loan_interest_deduction, Money::ZERO);
+        assert!(errors.is_empty());
+    }
+}
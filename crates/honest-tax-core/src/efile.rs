@@ -0,0 +1,98 @@
+//! IRS MeF-style structured XML export for output forms.
+
+use crate::outputs::Form1040;
+use crate::traits::{FormValue, OutputForm};
+use std::fmt::Write as _;
+
+/// Renders a completed output form into a structured, schema-tagged XML
+/// record stream, analogous to the electronic-filing payloads produced by
+/// the IRS Modernized e-File (MeF) system.
+pub trait EfileExport {
+    /// Serializes this form into an exchangeable electronic-filing XML
+    /// payload, with `tax_year` driving the schema version attribute.
+    fn to_efile_xml(&self) -> String;
+}
+
+/// Returns the e-file section a given Form 1040 line id belongs to,
+/// mirroring the income/deductions/tax/payments groupings already
+/// delineated in [`Form1040`]'s field layout.
+fn section_for(line_id: &str) -> &'static str {
+    match line_id {
+        "1a" | "1z" | "2b" | "3b" | "9" => "Income",
+        "10" | "11" | "12" | "14" | "15" => "DeductionsAndTaxableIncome",
+        "16" | "19" | "24" => "TaxAndCredits",
+        "25d" | "33" => "Payments",
+        "34" | "35a" | "37" => "RefundOrAmountOwed",
+        _ => "Other",
+    }
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl EfileExport for Form1040 {
+    fn to_efile_xml(&self) -> String {
+        let mut xml = String::new();
+        let _ = writeln!(
+            xml,
+            "<Return schemaVersion=\"{}\" formType=\"{}\">",
+            self.tax_year(),
+            escape_xml(&self.form_type().to_string())
+        );
+
+        let mut current_section: Option<&'static str> = None;
+        for line in self.lines() {
+            let section = section_for(&line.line_id);
+            if current_section != Some(section) {
+                if current_section.is_some() {
+                    let _ = writeln!(xml, "  </Section>");
+                }
+                let _ = writeln!(xml, "  <Section name=\"{}\">", section);
+                current_section = Some(section);
+            }
+
+            let amount = if let FormValue::Currency(money) = line.value {
+                money.as_decimal().to_string()
+            } else {
+                String::new()
+            };
+            let _ = writeln!(
+                xml,
+                "    <Line id=\"{}\" label=\"{}\">{}</Line>",
+                escape_xml(&line.line_id),
+                escape_xml(&line.label),
+                escape_xml(&amount)
+            );
+        }
+        if current_section.is_some() {
+            let _ = writeln!(xml, "  </Section>");
+        }
+
+        let _ = writeln!(xml, "</Return>");
+        xml
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::money::Money;
+
+    #[test]
+    fn test_to_efile_xml_wraps_lines_in_sections() {
+        let mut form = Form1040::new(2025);
+        form.line_1a = Money::from_dollars(60_000);
+        form.calculate_line_1z();
+
+        let xml = form.to_efile_xml();
+        assert!(xml.starts_with("<Return schemaVersion=\"2025\" formType=\"Form 1040\">"));
+        assert!(xml.contains("<Section name=\"Income\">"));
+        assert!(xml.contains("<Line id=\"1a\" label=\"Wages from W-2\">60000</Line>"));
+        assert!(xml.trim_end().ends_with("</Return>"));
+    }
+}
@@ -46,6 +46,10 @@ pub enum TaxError {
     #[error("tax rules not found for year {0}")]
     RulesNotFound(TaxYear),
 
+    /// State tax rules not found for the given state and year.
+    #[error("state tax rules not found for {state} in {year}")]
+    StateRulesNotFound { state: String, year: TaxYear },
+
     /// JSON parsing error.
     #[error("failed to parse JSON: {0}")]
     JsonParseError(#[from] serde_json::Error),
@@ -124,6 +128,11 @@ impl ValidationErrors {
         self.add(ValidationError::error(field, message));
     }
 
+    /// Adds a warning-level validation error.
+    pub fn add_warning(&mut self, field: impl Into<String>, message: impl Into<String>) {
+        self.add(ValidationError::warning(field, message));
+    }
+
     /// Returns true if there are any errors (not warnings).
     pub fn has_errors(&self) -> bool {
         self.errors
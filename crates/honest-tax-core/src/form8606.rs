@@ -0,0 +1,140 @@
+//! Form 8606: Nondeductible IRAs — basis tracking for IRA distributions.
+//!
+//! Implements the pro-rata basis recovery rule that determines how much of
+//! a traditional IRA distribution is taxable when the taxpayer has
+//! nondeductible contribution basis (e.g. backdoor/mega-backdoor Roth
+//! scenarios).
+
+use crate::money::Money;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Inputs to the Form 8606 nontaxable-distribution computation.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Form8606Inputs {
+    /// Total basis in nondeductible contributions carried from prior years.
+    pub prior_basis: Money,
+    /// Current-year nondeductible contributions.
+    pub current_year_contributions: Money,
+    /// Total distributions taken this year from all traditional IRAs.
+    pub distributions: Money,
+    /// Combined year-end value of all traditional IRAs.
+    pub year_end_value: Money,
+}
+
+/// Result of the Form 8606 nontaxable-distribution computation.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Form8606Result {
+    /// Line 4a: gross IRA distributions.
+    pub gross_distribution: Money,
+    /// Line 4b: taxable portion of IRA distributions.
+    pub taxable_distribution: Money,
+    /// Nontaxable portion of this year's distributions.
+    pub nontaxable_distribution: Money,
+    /// Remaining basis carried to next year.
+    pub remaining_basis: Money,
+}
+
+impl Form8606Inputs {
+    /// Computes the taxable/nontaxable split of this year's IRA
+    /// distributions.
+    ///
+    /// The nontaxable fraction is `(basis + contributions) / (value +
+    /// distributions)`, computed to six decimal places and capped at 1, so
+    /// the nontaxable amount never exceeds the distribution. The nontaxable
+    /// amount is the distribution times that fraction, capped at total
+    /// basis; everything else is taxable. If `value + distributions` is
+    /// zero, the fraction is zero (the entire distribution is taxable).
+    pub fn calculate(&self) -> Form8606Result {
+        let total_basis = self.prior_basis + self.current_year_contributions;
+        let denominator = self.year_end_value + self.distributions;
+
+        let fraction = if denominator.is_zero() {
+            Decimal::ZERO
+        } else {
+            (total_basis.as_decimal() / denominator.as_decimal())
+                .round_dp(6)
+                .min(Decimal::ONE)
+        };
+
+        let nontaxable = self.distributions.multiply_rate(fraction).min(total_basis);
+        let taxable = self.distributions.saturating_sub(nontaxable);
+        let remaining_basis = total_basis.saturating_sub(nontaxable);
+
+        Form8606Result {
+            gross_distribution: self.distributions,
+            taxable_distribution: taxable,
+            nontaxable_distribution: nontaxable,
+            remaining_basis,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backdoor_roth_no_prior_earnings_is_fully_nontaxable() {
+        let inputs = Form8606Inputs {
+            prior_basis: Money::ZERO,
+            current_year_contributions: Money::from_dollars(7_000),
+            distributions: Money::from_dollars(7_000),
+            year_end_value: Money::ZERO,
+        };
+
+        let result = inputs.calculate();
+        assert_eq!(result.gross_distribution, Money::from_dollars(7_000));
+        assert_eq!(result.taxable_distribution, Money::ZERO);
+        assert_eq!(result.nontaxable_distribution, Money::from_dollars(7_000));
+        assert_eq!(result.remaining_basis, Money::ZERO);
+    }
+
+    #[test]
+    fn test_partial_basis_pro_rates_the_distribution() {
+        // Basis $10,000, distribution $20,000, remaining value $80,000.
+        // Fraction = 10,000 / (80,000 + 20,000) = 0.10.
+        let inputs = Form8606Inputs {
+            prior_basis: Money::from_dollars(10_000),
+            current_year_contributions: Money::ZERO,
+            distributions: Money::from_dollars(20_000),
+            year_end_value: Money::from_dollars(80_000),
+        };
+
+        let result = inputs.calculate();
+        assert_eq!(result.nontaxable_distribution, Money::from_dollars(2_000));
+        assert_eq!(result.taxable_distribution, Money::from_dollars(18_000));
+        assert_eq!(result.remaining_basis, Money::from_dollars(8_000));
+    }
+
+    #[test]
+    fn test_zero_denominator_is_fully_taxable() {
+        let inputs = Form8606Inputs {
+            prior_basis: Money::from_dollars(1_000),
+            current_year_contributions: Money::ZERO,
+            distributions: Money::ZERO,
+            year_end_value: Money::ZERO,
+        };
+
+        let result = inputs.calculate();
+        assert_eq!(result.nontaxable_distribution, Money::ZERO);
+        assert_eq!(result.taxable_distribution, Money::ZERO);
+        assert_eq!(result.remaining_basis, Money::from_dollars(1_000));
+    }
+
+    #[test]
+    fn test_nontaxable_amount_never_exceeds_distribution() {
+        // Basis exceeds the distribution entirely; nontaxable can't exceed it.
+        let inputs = Form8606Inputs {
+            prior_basis: Money::from_dollars(5_000),
+            current_year_contributions: Money::ZERO,
+            distributions: Money::from_dollars(1_000),
+            year_end_value: Money::ZERO,
+        };
+
+        let result = inputs.calculate();
+        assert_eq!(result.nontaxable_distribution, Money::from_dollars(1_000));
+        assert_eq!(result.taxable_distribution, Money::ZERO);
+        assert_eq!(result.remaining_basis, Money::from_dollars(4_000));
+    }
+}
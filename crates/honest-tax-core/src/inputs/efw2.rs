@@ -0,0 +1,578 @@
+//! EFW2 (SSA "MMREF") fixed-width codec for [`W2`].
+//!
+//! The EFW2 format is a stream of fixed-length ASCII records, each keyed by
+//! a two-character record identifier in columns 1-2: `RA` (submitter),
+//! `RE` (employer), `RW` (employee wage), `RS` (state wage), `RT`
+//! (employer totals), and `RF` (final). Money fields are right-justified,
+//! zero-filled integers in cents with no decimal point; non-money fields
+//! are left-justified and space-padded.
+//!
+//! The column layout below is a simplified, internally-consistent subset
+//! of the real SSA specification, covering the fields [`W2`] models.
+
+use super::{W2StateInfo, W2};
+use crate::error::{TaxResult, ValidationErrors};
+use crate::money::Money;
+use crate::traits::TaxRules;
+use crate::types::TaxYear;
+use std::io::{Read, Write};
+
+/// Fixed record length, per the EFW2 specification.
+const RECORD_LEN: usize = 512;
+
+/// Width of a money field, in cents, zero-filled.
+const MONEY_WIDTH: usize = 11;
+
+fn ljust(value: &str, width: usize) -> String {
+    let mut field: String = value.chars().take(width).collect();
+    while field.chars().count() < width {
+        field.push(' ');
+    }
+    field
+}
+
+/// Checks that every EFW2 string field on `w2` is plain ASCII.
+///
+/// The format is a byte-for-byte fixed-width ASCII layout (per the SSA
+/// spec); a multi-byte UTF-8 character (e.g. in "José" or "Müller") would
+/// desync every field's byte offset from the layout the parser assumes and
+/// can land `pad_record`'s truncation mid-character. Rejecting non-ASCII
+/// input here, before any record is built, is simpler and safer than
+/// trying to make the fixed-width slicing byte/char-boundary-aware.
+fn check_efw2_ascii(w2: &W2) -> ValidationErrors {
+    let mut errors = ValidationErrors::new();
+    let fields: &[(&str, &str)] = &[
+        ("employer_ein", &w2.employer_ein),
+        ("employer_name", &w2.employer_name),
+        ("employee_ssn", &w2.employee_ssn),
+        ("employee_first_name", &w2.employee_first_name),
+        ("employee_last_name", &w2.employee_last_name),
+    ];
+    for (field, value) in fields {
+        if !value.is_ascii() {
+            errors.add_error(
+                *field,
+                format!("EFW2 fields must be plain ASCII, got {value:?}"),
+            );
+        }
+    }
+    for state in &w2.state_info {
+        if !state.state.is_ascii() || !state.employer_state_id.is_ascii() {
+            errors.add_error(
+                "state_info",
+                format!(
+                    "EFW2 state fields must be plain ASCII, got {:?}/{:?}",
+                    state.state, state.employer_state_id
+                ),
+            );
+        }
+    }
+    errors
+}
+
+/// The largest cent value [`money_field`] can represent in [`MONEY_WIDTH`]
+/// zero-filled digits: `$999,999,999.99`. This is much tighter than
+/// [`crate::usd_amount::MAX_DOLLARS`], which bounds `Money`/`UsdAmount` in
+/// general, not just the EFW2 wire format.
+const MONEY_FIELD_MAX_CENTS: i64 = 10i64.pow(MONEY_WIDTH as u32) - 1;
+
+/// Checks that every EFW2 money field on `w2` (and its state records) is
+/// non-negative and fits in [`MONEY_WIDTH`] digits of cents.
+///
+/// `money_field` zero-fills to a *minimum* width, not a maximum, so a value
+/// wider than [`MONEY_WIDTH`] silently overruns into the next column instead
+/// of erroring, and a negative value would silently go through `.max(0)` as
+/// zero. Catching both here, before any record is built, keeps `money_field`
+/// itself a simple, infallible formatter.
+fn check_efw2_money_bounds(w2: &W2) -> ValidationErrors {
+    let mut errors = ValidationErrors::new();
+    let fields: &[(&str, Money)] = &[
+        ("box_1_wages", w2.box_1_wages),
+        ("box_2_federal_tax_withheld", w2.box_2_federal_tax_withheld),
+        ("box_3_social_security_wages", w2.box_3_social_security_wages),
+        (
+            "box_4_social_security_tax_withheld",
+            w2.box_4_social_security_tax_withheld,
+        ),
+        ("box_5_medicare_wages", w2.box_5_medicare_wages),
+        ("box_6_medicare_tax_withheld", w2.box_6_medicare_tax_withheld),
+        ("box_7_social_security_tips", w2.box_7_social_security_tips),
+        ("box_8_allocated_tips", w2.box_8_allocated_tips),
+        (
+            "box_10_dependent_care_benefits",
+            w2.box_10_dependent_care_benefits,
+        ),
+        ("box_11_nonqualified_plans", w2.box_11_nonqualified_plans),
+    ];
+    for (field, value) in fields {
+        check_money_field_bounds(&mut errors, (*field).to_string(), *value);
+    }
+    for (i, state) in w2.state_info.iter().enumerate() {
+        check_money_field_bounds(
+            &mut errors,
+            format!("state_info[{i}].state_wages"),
+            state.state_wages,
+        );
+        check_money_field_bounds(
+            &mut errors,
+            format!("state_info[{i}].state_tax_withheld"),
+            state.state_tax_withheld,
+        );
+    }
+    errors
+}
+
+fn check_money_field_bounds(errors: &mut ValidationErrors, field: String, value: Money) {
+    let cents = value.as_cents();
+    if cents < 0 {
+        errors.add_error(
+            field,
+            format!(
+                "EFW2 money fields must not be negative, got {}",
+                value.as_decimal()
+            ),
+        );
+    } else if cents > MONEY_FIELD_MAX_CENTS {
+        errors.add_error(
+            field,
+            format!(
+                "EFW2 money fields are limited to {MONEY_WIDTH} digits of cents \
+                 (max $999,999,999.99), got {}",
+                value.as_decimal()
+            ),
+        );
+    }
+}
+
+fn money_field(amount: Money) -> String {
+    format!("{:0width$}", amount.as_cents().max(0), width = MONEY_WIDTH)
+}
+
+fn parse_money_field(
+    raw: &str,
+    record_type: &str,
+    offset: usize,
+    errors: &mut ValidationErrors,
+) -> Money {
+    match raw.trim().parse::<i64>() {
+        Ok(cents) => Money::from_cents(cents),
+        Err(_) => {
+            errors.add_error(
+                format!("{record_type}@{offset}"),
+                format!("expected a zero-filled cents field, got {raw:?}"),
+            );
+            Money::ZERO
+        }
+    }
+}
+
+fn pad_record(mut record: String) -> String {
+    debug_assert!(record.is_ascii(), "EFW2 record must be plain ASCII before padding");
+    while record.len() < RECORD_LEN {
+        record.push(' ');
+    }
+    record.truncate(RECORD_LEN);
+    record
+}
+
+fn build_ra_record() -> String {
+    pad_record("RA".to_string())
+}
+
+fn build_re_record(tax_year: TaxYear, employer_ein: &str, employer_name: &str) -> String {
+    let mut record = String::new();
+    record.push_str("RE");
+    record.push_str(&format!("{tax_year:04}"));
+    record.push_str(&ljust(employer_ein, 10));
+    record.push_str(&ljust(employer_name, 40));
+    pad_record(record)
+}
+
+fn build_rw_record(w2: &W2) -> String {
+    let mut record = String::new();
+    record.push_str("RW");
+    record.push_str(&ljust(&w2.employee_ssn, 11));
+    record.push_str(&ljust(&w2.employee_first_name, 20));
+    record.push_str(&ljust(&w2.employee_last_name, 20));
+    record.push_str(&money_field(w2.box_1_wages));
+    record.push_str(&money_field(w2.box_2_federal_tax_withheld));
+    record.push_str(&money_field(w2.box_3_social_security_wages));
+    record.push_str(&money_field(w2.box_4_social_security_tax_withheld));
+    record.push_str(&money_field(w2.box_5_medicare_wages));
+    record.push_str(&money_field(w2.box_6_medicare_tax_withheld));
+    record.push_str(&money_field(w2.box_7_social_security_tips));
+    record.push_str(&money_field(w2.box_8_allocated_tips));
+    record.push_str(&money_field(w2.box_10_dependent_care_benefits));
+    record.push_str(&money_field(w2.box_11_nonqualified_plans));
+    pad_record(record)
+}
+
+fn build_rs_record(state: &W2StateInfo) -> String {
+    let mut record = String::new();
+    record.push_str("RS");
+    record.push_str(&ljust(&state.state, 2));
+    record.push_str(&ljust(&state.employer_state_id, 9));
+    record.push_str(&money_field(state.state_wages));
+    record.push_str(&money_field(state.state_tax_withheld));
+    pad_record(record)
+}
+
+fn build_rt_record(rw_count: u64, total_box1: Money, total_box2: Money) -> String {
+    let mut record = String::new();
+    record.push_str("RT");
+    record.push_str(&format!("{rw_count:0width$}", width = MONEY_WIDTH));
+    record.push_str(&money_field(total_box1));
+    record.push_str(&money_field(total_box2));
+    pad_record(record)
+}
+
+fn build_rf_record(total_rw_count: u64) -> String {
+    let mut record = String::new();
+    record.push_str("RF");
+    record.push_str(&format!("{total_rw_count:0width$}", width = MONEY_WIDTH));
+    pad_record(record)
+}
+
+impl W2 {
+    /// Parses a stream of EFW2 records into a list of `W2`s.
+    ///
+    /// Detects the tax year from each `RE` record, groups subsequent `RW`
+    /// (employee wage) and `RS` (state wage) records under the employer
+    /// named by the most recent `RE`, and surfaces malformed records as
+    /// `ValidationErrors` keyed by record type and column offset.
+    pub fn from_efw2<R: Read>(mut reader: R) -> TaxResult<Vec<W2>> {
+        let mut data = String::new();
+        reader.read_to_string(&mut data)?;
+
+        let mut errors = ValidationErrors::new();
+        let mut result: Vec<W2> = Vec::new();
+        let mut current_tax_year: TaxYear = 0;
+        let mut employer_ein = String::new();
+        let mut employer_name = String::new();
+        let mut next_id = 0usize;
+
+        for (line_no, raw_line) in data.lines().enumerate() {
+            if raw_line.trim().is_empty() {
+                continue;
+            }
+            if !raw_line.is_ascii() {
+                errors.add_error(
+                    format!("record[{line_no}]@0"),
+                    "EFW2 records must be plain ASCII",
+                );
+                continue;
+            }
+            if raw_line.len() != RECORD_LEN {
+                errors.add_error(
+                    format!("record[{line_no}]@0"),
+                    format!(
+                        "expected a {RECORD_LEN}-byte record, got {} bytes",
+                        raw_line.len()
+                    ),
+                );
+                continue;
+            }
+
+            let record_type = &raw_line[0..2];
+            match record_type {
+                "RE" => {
+                    current_tax_year = raw_line[2..6].trim().parse().unwrap_or(current_tax_year);
+                    employer_ein = raw_line[6..16].trim().to_string();
+                    employer_name = raw_line[16..56].trim().to_string();
+                }
+                "RW" => {
+                    next_id += 1;
+                    result.push(W2 {
+                        id: format!("efw2-{next_id}"),
+                        tax_year: current_tax_year,
+                        employer_ein: employer_ein.clone(),
+                        employer_name: employer_name.clone(),
+                        employee_ssn: raw_line[2..13].trim().to_string(),
+                        employee_first_name: raw_line[13..33].trim().to_string(),
+                        employee_last_name: raw_line[33..53].trim().to_string(),
+                        box_1_wages: parse_money_field(&raw_line[53..64], "RW", 53, &mut errors),
+                        box_2_federal_tax_withheld: parse_money_field(
+                            &raw_line[64..75],
+                            "RW",
+                            64,
+                            &mut errors,
+                        ),
+                        box_3_social_security_wages: parse_money_field(
+                            &raw_line[75..86],
+                            "RW",
+                            75,
+                            &mut errors,
+                        ),
+                        box_4_social_security_tax_withheld: parse_money_field(
+                            &raw_line[86..97],
+                            "RW",
+                            86,
+                            &mut errors,
+                        ),
+                        box_5_medicare_wages: parse_money_field(
+                            &raw_line[97..108],
+                            "RW",
+                            97,
+                            &mut errors,
+                        ),
+                        box_6_medicare_tax_withheld: parse_money_field(
+                            &raw_line[108..119],
+                            "RW",
+                            108,
+                            &mut errors,
+                        ),
+                        box_7_social_security_tips: parse_money_field(
+                            &raw_line[119..130],
+                            "RW",
+                            119,
+                            &mut errors,
+                        ),
+                        box_8_allocated_tips: parse_money_field(
+                            &raw_line[130..141],
+                            "RW",
+                            130,
+                            &mut errors,
+                        ),
+                        box_10_dependent_care_benefits: parse_money_field(
+                            &raw_line[141..152],
+                            "RW",
+                            141,
+                            &mut errors,
+                        ),
+                        box_11_nonqualified_plans: parse_money_field(
+                            &raw_line[152..163],
+                            "RW",
+                            152,
+                            &mut errors,
+                        ),
+                        ..W2::default()
+                    });
+                }
+                "RS" => {
+                    let state = raw_line[2..4].trim().to_string();
+                    let employer_state_id = raw_line[4..13].trim().to_string();
+                    let state_wages = parse_money_field(&raw_line[13..24], "RS", 13, &mut errors);
+                    let state_tax_withheld =
+                        parse_money_field(&raw_line[24..35], "RS", 24, &mut errors);
+
+                    match result.last_mut() {
+                        Some(w2) => w2.state_info.push(W2StateInfo {
+                            state,
+                            employer_state_id,
+                            state_wages,
+                            state_tax_withheld,
+                        }),
+                        None => errors.add_error(
+                            format!("record[{line_no}]@0"),
+                            "RS record with no preceding RW record",
+                        ),
+                    }
+                }
+                "RA" | "RT" | "RF" => {
+                    // Submitter/totals/final records carry no per-employee data.
+                }
+                other => {
+                    errors.add_error(
+                        format!("record[{line_no}]@0"),
+                        format!("unrecognized record type {other:?}"),
+                    );
+                }
+            }
+        }
+
+        errors.into_result()?;
+        Ok(result)
+    }
+}
+
+/// Writes a slice of `W2`s as a stream of EFW2 records, grouped by
+/// employer, with `RT` totals computed by summing each employer's `RW`
+/// records. Each `W2` is validated before any bytes are written, so a
+/// malformed record can't reconcile into a total that silently hides it.
+pub fn to_efw2<W: Write>(w2s: &[W2], mut writer: W, rules: &dyn TaxRules) -> TaxResult<()> {
+    if w2s.is_empty() {
+        return Ok(());
+    }
+
+    for w2 in w2s {
+        w2.validate(rules)?;
+        check_efw2_ascii(w2).into_result()?;
+        check_efw2_money_bounds(w2).into_result()?;
+    }
+
+    let tax_year = w2s[0].tax_year;
+    let mut employers: Vec<(String, String)> = Vec::new();
+    for w2 in w2s {
+        let key = (w2.employer_ein.clone(), w2.employer_name.clone());
+        if !employers.contains(&key) {
+            employers.push(key);
+        }
+    }
+
+    writeln!(writer, "{}", build_ra_record())?;
+
+    let mut total_rw_count = 0u64;
+    for (ein, name) in &employers {
+        writeln!(writer, "{}", build_re_record(tax_year, ein, name))?;
+
+        let mut total_box1 = Money::ZERO;
+        let mut total_box2 = Money::ZERO;
+        let mut rw_count = 0u64;
+
+        for w2 in w2s
+            .iter()
+            .filter(|w2| &w2.employer_ein == ein && &w2.employer_name == name)
+        {
+            writeln!(writer, "{}", build_rw_record(w2))?;
+            for state in &w2.state_info {
+                writeln!(writer, "{}", build_rs_record(state))?;
+            }
+            // Checked, not `+=`: these totals sum wages reported on
+            // caller-supplied W-2s across an entire employer, an untrusted
+            // aggregate that should fail loudly on overflow rather than wrap.
+            total_box1 = total_box1.checked_add(w2.box_1_wages)?;
+            total_box2 = total_box2.checked_add(w2.box_2_federal_tax_withheld)?;
+            rw_count += 1;
+        }
+
+        // `checked_add` above only guards against overflowing `Money` itself;
+        // the employer totals can still exceed what an `RT` record's money
+        // fields can hold even when every contributing `RW` is in bounds.
+        let mut totals_errors = ValidationErrors::new();
+        check_money_field_bounds(&mut totals_errors, "total_box1".to_string(), total_box1);
+        check_money_field_bounds(&mut totals_errors, "total_box2".to_string(), total_box2);
+        totals_errors.into_result()?;
+
+        writeln!(writer, "{}", build_rt_record(rw_count, total_box1, total_box2))?;
+        total_rw_count += rw_count;
+    }
+
+    writeln!(writer, "{}", build_rf_record(total_rw_count))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inputs::W2StateInfo;
+
+    fn sample_w2() -> W2 {
+        W2 {
+            id: "w2-001".to_string(),
+            tax_year: 2025,
+            employer_ein: "12-3456789".to_string(),
+            employer_name: "Acme Corp".to_string(),
+            employee_ssn: "123-45-6789".to_string(),
+            employee_first_name: "John".to_string(),
+            employee_last_name: "Doe".to_string(),
+            box_1_wages: Money::from_dollars(75_000),
+            box_2_federal_tax_withheld: Money::from_dollars(10_000),
+            box_3_social_security_wages: Money::from_dollars(75_000),
+            box_4_social_security_tax_withheld: Money::from_cents(465_000),
+            box_5_medicare_wages: Money::from_dollars(75_000),
+            box_6_medicare_tax_withheld: Money::from_cents(108_750),
+            state_info: vec![W2StateInfo {
+                state: "CA".to_string(),
+                employer_state_id: "123456789".to_string(),
+                state_wages: Money::from_dollars(75_000),
+                state_tax_withheld: Money::from_dollars(5_000),
+            }],
+            ..W2::default()
+        }
+    }
+
+    #[test]
+    fn test_round_trip_through_efw2() {
+        let rules = crate::rules::Rules2025::new();
+        let original = vec![sample_w2()];
+        let mut buf: Vec<u8> = Vec::new();
+        to_efw2(&original, &mut buf, &rules).unwrap();
+
+        let parsed = W2::from_efw2(buf.as_slice()).unwrap();
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].tax_year, 2025);
+        assert_eq!(parsed[0].employer_ein, "12-3456789");
+        assert_eq!(parsed[0].employer_name, "Acme Corp");
+        assert_eq!(parsed[0].employee_ssn, "123-45-6789");
+        assert_eq!(parsed[0].box_1_wages, Money::from_dollars(75_000));
+        assert_eq!(parsed[0].state_info.len(), 1);
+        assert_eq!(parsed[0].state_info[0].state, "CA");
+        assert_eq!(
+            parsed[0].state_info[0].state_wages,
+            Money::from_dollars(75_000)
+        );
+    }
+
+    #[test]
+    fn test_from_efw2_rejects_short_record() {
+        let data = "RA short record\n";
+        let result = W2::from_efw2(data.as_bytes());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_to_efw2_rejects_invalid_w2() {
+        let rules = crate::rules::Rules2025::new();
+        let mut invalid = sample_w2();
+        invalid.employee_ssn = "invalid".to_string();
+        let mut buf: Vec<u8> = Vec::new();
+        assert!(to_efw2(&[invalid], &mut buf, &rules).is_err());
+    }
+
+    #[test]
+    fn test_to_efw2_rejects_non_ascii_name() {
+        let rules = crate::rules::Rules2025::new();
+        let mut non_ascii = sample_w2();
+        non_ascii.employee_first_name = "José".to_string();
+        let mut buf: Vec<u8> = Vec::new();
+        assert!(to_efw2(&[non_ascii], &mut buf, &rules).is_err());
+    }
+
+    #[test]
+    fn test_to_efw2_rejects_employer_total_overflow() {
+        // Each box 1 fits an RW record on its own; only the employer's
+        // summed total is too wide for the RT record's money field.
+        let rules = crate::rules::Rules2025::new();
+        let mut a = sample_w2();
+        a.id = "w2-a".to_string();
+        a.box_1_wages = Money::from_cents(MONEY_FIELD_MAX_CENTS);
+        let mut b = sample_w2();
+        b.id = "w2-b".to_string();
+        b.box_1_wages = Money::from_cents(MONEY_FIELD_MAX_CENTS);
+
+        let mut buf: Vec<u8> = Vec::new();
+        assert!(to_efw2(&[a, b], &mut buf, &rules).is_err());
+    }
+
+    #[test]
+    fn test_to_efw2_rejects_box_exceeding_money_field_width() {
+        let rules = crate::rules::Rules2025::new();
+        let mut too_large = sample_w2();
+        too_large.box_1_wages = Money::from_cents(MONEY_FIELD_MAX_CENTS + 1);
+        let mut buf: Vec<u8> = Vec::new();
+        assert!(to_efw2(&[too_large], &mut buf, &rules).is_err());
+    }
+
+    #[test]
+    fn test_to_efw2_rejects_negative_box_not_covered_by_w2_validate() {
+        // `W2::validate`/`validation_errors` have no negativity check for
+        // box_4 (its payroll-tax cross-check is only a warning), so this
+        // exercises `check_efw2_money_bounds` specifically rather than the
+        // pre-existing box 1/2/3 negativity checks.
+        let rules = crate::rules::Rules2025::new();
+        let mut negative = sample_w2();
+        negative.box_4_social_security_tax_withheld = Money::from_dollars(-1);
+        let mut buf: Vec<u8> = Vec::new();
+        assert!(to_efw2(&[negative], &mut buf, &rules).is_err());
+    }
+
+    #[test]
+    fn test_from_efw2_rejects_non_ascii_record() {
+        let mut record = String::from("RW");
+        record.push_str(&"é".repeat(RECORD_LEN - 2));
+        let data = format!("{record}\n");
+        let result = W2::from_efw2(data.as_bytes());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,182 @@
+//! Form 1099-DIV: Dividends and Distributions
+
+use crate::money::Money;
+use crate::traits::{ForeignIncomeItem, InputForm};
+use crate::types::{InputFormType, TaxYear};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Form 1099-DIV: Dividends and Distributions
+///
+/// Represents a dividend income statement received from a brokerage or
+/// other payer. Box numbers correspond to the official IRS 1099-DIV form.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Form1099Div {
+    /// Unique identifier for this 1099-DIV instance.
+    pub id: String,
+
+    /// Tax year this 1099-DIV is for.
+    pub tax_year: TaxYear,
+
+    /// Payer's name.
+    #[serde(default)]
+    pub payer_name: String,
+
+    /// Box 1a: Total ordinary dividends.
+    #[serde(default)]
+    pub box_1a_ordinary_dividends: Money,
+
+    /// Box 1b: Qualified dividends.
+    #[serde(default)]
+    pub box_1b_qualified_dividends: Money,
+
+    /// Box 2a: Total capital gain distributions.
+    #[serde(default)]
+    pub box_2a_capital_gain_distributions: Money,
+
+    /// Box 4: Federal income tax withheld.
+    #[serde(default)]
+    pub box_4_federal_tax_withheld: Money,
+
+    /// Box 7: Foreign tax paid.
+    #[serde(default)]
+    pub box_7_foreign_tax_paid: Money,
+
+    /// Foreign country or U.S. possession the Box 7 tax was paid to. The IRS
+    /// permits "RIC" here when the tax is pooled across a regulated
+    /// investment company's multiple source countries.
+    #[serde(default)]
+    pub foreign_country: String,
+
+    /// Portion of Box 1a that is foreign-source income, as reported on the
+    /// payer's RIC supplemental statement. A fund holding both US and
+    /// foreign securities only has foreign-source income on this portion,
+    /// not on the full Box 1a total, so this (not Box 1a) is the Form 1116
+    /// foreign-source-income base.
+    #[serde(default)]
+    pub box_1a_foreign_source_dividends: Money,
+}
+
+impl InputForm for Form1099Div {
+    fn form_type(&self) -> InputFormType {
+        InputFormType::F1099Div
+    }
+
+    fn tax_year(&self) -> TaxYear {
+        self.tax_year
+    }
+
+    fn form_id(&self) -> &str {
+        &self.id
+    }
+
+    fn ordinary_dividends(&self) -> Option<Money> {
+        Some(self.box_1a_ordinary_dividends)
+    }
+
+    fn qualified_dividends(&self) -> Option<Money> {
+        Some(self.box_1b_qualified_dividends)
+    }
+
+    fn capital_gain_distributions(&self) -> Option<Money> {
+        Some(self.box_2a_capital_gain_distributions)
+    }
+
+    fn federal_withholding(&self) -> Option<Money> {
+        Some(self.box_4_federal_tax_withheld)
+    }
+
+    fn payer_name(&self) -> Option<&str> {
+        Some(&self.payer_name)
+    }
+
+    fn foreign_income(&self) -> Vec<ForeignIncomeItem> {
+        self.foreign_tax_paid()
+    }
+
+    fn foreign_tax_paid(&self) -> Vec<ForeignIncomeItem> {
+        if self.box_7_foreign_tax_paid.is_zero() {
+            return Vec::new();
+        }
+        let foreign_source = self
+            .box_1a_foreign_source_dividends
+            .min(self.box_1a_ordinary_dividends);
+        vec![ForeignIncomeItem {
+            income_type: "dividend".to_string(),
+            source_currency: "USD".to_string(),
+            source_amount: foreign_source.as_decimal(),
+            exchange_rate: Decimal::ONE,
+            converted: foreign_source,
+            foreign_tax_paid: self.box_7_foreign_tax_paid,
+            country_code: self.foreign_country.clone(),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Form1099Div {
+        Form1099Div {
+            id: "1099div-001".to_string(),
+            tax_year: 2025,
+            payer_name: "Brokerage Co".to_string(),
+            box_1a_ordinary_dividends: Money::from_dollars(1_200),
+            box_1b_qualified_dividends: Money::from_dollars(1_000),
+            box_2a_capital_gain_distributions: Money::from_dollars(300),
+            box_4_federal_tax_withheld: Money::ZERO,
+            box_7_foreign_tax_paid: Money::ZERO,
+            foreign_country: String::new(),
+            box_1a_foreign_source_dividends: Money::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_1099_div_input_form_trait() {
+        let form = sample();
+        assert_eq!(form.form_type(), InputFormType::F1099Div);
+        assert_eq!(form.ordinary_dividends(), Some(Money::from_dollars(1_200)));
+        assert_eq!(form.qualified_dividends(), Some(Money::from_dollars(1_000)));
+        assert_eq!(
+            form.capital_gain_distributions(),
+            Some(Money::from_dollars(300))
+        );
+    }
+
+    #[test]
+    fn test_no_foreign_income_when_box_7_is_zero() {
+        let form = sample();
+        assert!(form.foreign_income().is_empty());
+        assert!(form.foreign_tax_paid().is_empty());
+    }
+
+    #[test]
+    fn test_foreign_tax_paid_produces_item_with_conversion() {
+        let mut form = sample();
+        form.box_7_foreign_tax_paid = Money::from_dollars(75);
+        form.foreign_country = "RIC".to_string();
+        form.box_1a_foreign_source_dividends = Money::from_dollars(400);
+
+        let items = form.foreign_tax_paid();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].foreign_tax_paid, Money::from_dollars(75));
+        assert_eq!(items[0].country_code, "RIC");
+        assert_eq!(items[0].converted, Money::from_dollars(400));
+
+        assert_eq!(form.foreign_income(), items);
+    }
+
+    #[test]
+    fn test_foreign_source_dividends_are_capped_at_box_1a() {
+        let mut form = sample();
+        form.box_7_foreign_tax_paid = Money::from_dollars(75);
+        form.foreign_country = "RIC".to_string();
+        // A misreported value exceeding total ordinary dividends must not
+        // inflate the foreign-source-income base beyond Box 1a.
+        form.box_1a_foreign_source_dividends = Money::from_dollars(5_000);
+
+        let items = form.foreign_tax_paid();
+        assert_eq!(items[0].converted, Money::from_dollars(1_200));
+    }
+}
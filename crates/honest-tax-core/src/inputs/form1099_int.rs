@@ -0,0 +1,89 @@
+//! Form 1099-INT: Interest Income
+
+use crate::money::Money;
+use crate::traits::InputForm;
+use crate::types::{InputFormType, TaxYear};
+use serde::{Deserialize, Serialize};
+
+/// Form 1099-INT: Interest Income
+///
+/// Represents an interest income statement received from a bank or other
+/// payer. Box numbers correspond to the official IRS 1099-INT form.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Form1099Int {
+    /// Unique identifier for this 1099-INT instance.
+    pub id: String,
+
+    /// Tax year this 1099-INT is for.
+    pub tax_year: TaxYear,
+
+    /// Payer's name.
+    #[serde(default)]
+    pub payer_name: String,
+
+    /// Box 1: Interest income.
+    #[serde(default)]
+    pub box_1_interest_income: Money,
+
+    /// Box 4: Federal income tax withheld.
+    #[serde(default)]
+    pub box_4_federal_tax_withheld: Money,
+
+    /// Box 8: Tax-exempt interest.
+    #[serde(default)]
+    pub box_8_tax_exempt_interest: Money,
+}
+
+impl InputForm for Form1099Int {
+    fn form_type(&self) -> InputFormType {
+        InputFormType::F1099Int
+    }
+
+    fn tax_year(&self) -> TaxYear {
+        self.tax_year
+    }
+
+    fn form_id(&self) -> &str {
+        &self.id
+    }
+
+    fn taxable_interest(&self) -> Option<Money> {
+        Some(self.box_1_interest_income)
+    }
+
+    fn tax_exempt_interest(&self) -> Option<Money> {
+        Some(self.box_8_tax_exempt_interest)
+    }
+
+    fn federal_withholding(&self) -> Option<Money> {
+        Some(self.box_4_federal_tax_withheld)
+    }
+
+    fn payer_name(&self) -> Option<&str> {
+        Some(&self.payer_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Form1099Int {
+        Form1099Int {
+            id: "1099int-001".to_string(),
+            tax_year: 2025,
+            payer_name: "First National Bank".to_string(),
+            box_1_interest_income: Money::from_dollars(500),
+            box_4_federal_tax_withheld: Money::ZERO,
+            box_8_tax_exempt_interest: Money::from_dollars(100),
+        }
+    }
+
+    #[test]
+    fn test_1099_int_input_form_trait() {
+        let form = sample();
+        assert_eq!(form.form_type(), InputFormType::F1099Int);
+        assert_eq!(form.taxable_interest(), Some(Money::from_dollars(500)));
+        assert_eq!(form.tax_exempt_interest(), Some(Money::from_dollars(100)));
+    }
+}
@@ -0,0 +1,94 @@
+//! Form 1099-R: Distributions From Pensions, Annuities, Retirement, etc.
+
+use crate::money::Money;
+use crate::traits::InputForm;
+use crate::types::{InputFormType, TaxYear};
+use serde::{Deserialize, Serialize};
+
+/// Form 1099-R: Distributions From Pensions, Annuities, Retirement, etc.
+///
+/// Represents a retirement distribution statement received from a plan
+/// administrator or payer. Box numbers correspond to the official IRS
+/// 1099-R form.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Form1099R {
+    /// Unique identifier for this 1099-R instance.
+    pub id: String,
+
+    /// Tax year this 1099-R is for.
+    pub tax_year: TaxYear,
+
+    /// Payer's name.
+    #[serde(default)]
+    pub payer_name: String,
+
+    /// Box 1: Gross distribution.
+    #[serde(default)]
+    pub box_1_gross_distribution: Money,
+
+    /// Box 2a: Taxable amount.
+    #[serde(default)]
+    pub box_2a_taxable_amount: Money,
+
+    /// Box 4: Federal income tax withheld.
+    #[serde(default)]
+    pub box_4_federal_tax_withheld: Money,
+}
+
+impl InputForm for Form1099R {
+    fn form_type(&self) -> InputFormType {
+        InputFormType::F1099R
+    }
+
+    fn tax_year(&self) -> TaxYear {
+        self.tax_year
+    }
+
+    fn form_id(&self) -> &str {
+        &self.id
+    }
+
+    fn pension_gross(&self) -> Option<Money> {
+        Some(self.box_1_gross_distribution)
+    }
+
+    fn pension_taxable(&self) -> Option<Money> {
+        Some(self.box_2a_taxable_amount)
+    }
+
+    fn federal_withholding(&self) -> Option<Money> {
+        Some(self.box_4_federal_tax_withheld)
+    }
+
+    fn payer_name(&self) -> Option<&str> {
+        Some(&self.payer_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Form1099R {
+        Form1099R {
+            id: "1099r-001".to_string(),
+            tax_year: 2025,
+            payer_name: "Pension Fund Trust".to_string(),
+            box_1_gross_distribution: Money::from_dollars(20_000),
+            box_2a_taxable_amount: Money::from_dollars(18_000),
+            box_4_federal_tax_withheld: Money::from_dollars(2_000),
+        }
+    }
+
+    #[test]
+    fn test_1099_r_input_form_trait() {
+        let form = sample();
+        assert_eq!(form.form_type(), InputFormType::F1099R);
+        assert_eq!(form.pension_gross(), Some(Money::from_dollars(20_000)));
+        assert_eq!(form.pension_taxable(), Some(Money::from_dollars(18_000)));
+        assert_eq!(
+            form.federal_withholding(),
+            Some(Money::from_dollars(2_000))
+        );
+    }
+}
@@ -0,0 +1,13 @@
+//! Input forms (documents received by the taxpayer).
+
+mod efw2;
+mod form1099_div;
+mod form1099_int;
+mod form1099_r;
+mod w2;
+
+pub use efw2::to_efw2;
+pub use form1099_div::Form1099Div;
+pub use form1099_int::Form1099Int;
+pub use form1099_r::Form1099R;
+pub use w2::{W2Box12, W2LocalInfo, W2StateInfo, W2};
@@ -2,9 +2,14 @@
 
 use crate::error::{TaxResult, ValidationErrors};
 use crate::money::Money;
-use crate::traits::InputForm;
-use crate::types::{InputFormType, TaxYear};
+use crate::traits::{InputForm, TaxRules};
+use crate::types::{FilingStatus, InputFormType, TaxYear};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Default rounding tolerance applied when cross-checking payroll-tax boxes
+/// against statutory rates in [`W2::validate`].
+const DEFAULT_PAYROLL_TAX_TOLERANCE_CENTS: i64 = 100;
 
 /// Form W-2: Wage and Tax Statement
 ///
@@ -89,6 +94,11 @@ pub struct W2 {
     #[serde(default)]
     pub box_11_nonqualified_plans: Money,
 
+    /// Box 14: Tier 1 Railroad Retirement Tax Act (RRTA) tax withheld, for
+    /// railroad employees (who have no Social Security tax withholding).
+    #[serde(default)]
+    pub box_14_rrta_tier1_tax_withheld: Money,
+
     /// Box 12: Codes and amounts (various compensation types).
     #[serde(default)]
     pub box_12: Vec<W2Box12>,
@@ -143,6 +153,7 @@ impl Default for W2 {
             box_8_allocated_tips: Money::ZERO,
             box_10_dependent_care_benefits: Money::ZERO,
             box_11_nonqualified_plans: Money::ZERO,
+            box_14_rrta_tier1_tax_withheld: Money::ZERO,
             box_12: Vec::new(),
             box_13_statutory_employee: false,
             box_13_retirement_plan: false,
@@ -162,6 +173,48 @@ pub struct W2Box12 {
     pub amount: Money,
 }
 
+impl W2Box12 {
+    /// Returns the category this code's amount falls into.
+    pub fn category(&self) -> Box12Category {
+        Box12Category::for_code(&self.code)
+    }
+}
+
+/// Classifies a Box 12 code by what its amount represents, so downstream
+/// calculators can feed retirement and HSA figures into AGI adjustments
+/// without re-parsing raw codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Box12Category {
+    /// Elective deferrals to employer retirement plans (D/E/F/G/H/S/AA/BB/EE),
+    /// subject to the § 402(g) annual limit.
+    ElectiveDeferral,
+    /// Employee HSA contributions through a cafeteria plan (W).
+    HsaContribution,
+    /// Cost of employer-sponsored health coverage (DD); informational only,
+    /// not included in income.
+    EmployerHealthCoverage,
+    /// Uncollected Social Security, Medicare, or RRTA tax (A/B/M/N).
+    UncollectedTax,
+    /// Any other valid code not covered by a more specific category.
+    Other,
+}
+
+impl Box12Category {
+    /// Returns the category for a given Box 12 code.
+    ///
+    /// Unrecognized codes are classified as `Other` rather than panicking;
+    /// code validity itself is checked separately during `W2::validate`.
+    pub fn for_code(code: &str) -> Self {
+        match code {
+            "D" | "E" | "F" | "G" | "H" | "S" | "AA" | "BB" | "EE" => Self::ElectiveDeferral,
+            "W" => Self::HsaContribution,
+            "DD" => Self::EmployerHealthCoverage,
+            "A" | "B" | "M" | "N" => Self::UncollectedTax,
+            _ => Self::Other,
+        }
+    }
+}
+
 /// State tax information from W-2.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct W2StateInfo {
@@ -188,7 +241,24 @@ pub struct W2LocalInfo {
 
 impl W2 {
     /// Validates the W-2 data and returns any errors.
-    pub fn validate(&self) -> TaxResult<()> {
+    ///
+    /// Payroll-tax boxes are cross-checked against `rules`' statutory rates
+    /// within a default ±$1.00 rounding tolerance; use
+    /// [`W2::validate_with_tolerance`] to widen or tighten that tolerance.
+    pub fn validate(&self, rules: &dyn TaxRules) -> TaxResult<()> {
+        self.validate_with_tolerance(rules, Money::from_cents(DEFAULT_PAYROLL_TAX_TOLERANCE_CENTS))
+    }
+
+    /// Like [`W2::validate`], with an explicit tolerance for the payroll-tax
+    /// consistency checks.
+    pub fn validate_with_tolerance(&self, rules: &dyn TaxRules, tolerance: Money) -> TaxResult<()> {
+        self.validation_errors(rules, tolerance).into_result()
+    }
+
+    /// Returns every validation error and warning for this W-2, including
+    /// the payroll-tax consistency checks. Unlike [`W2::validate`], warnings
+    /// (which don't fail validation) are visible here.
+    pub fn validation_errors(&self, rules: &dyn TaxRules, tolerance: Money) -> ValidationErrors {
         let mut errors = ValidationErrors::new();
 
         // Validate SSN format (XXX-XX-XXXX) - only if provided
@@ -228,7 +298,105 @@ impl W2 {
             }
         }
 
-        errors.into_result()
+        // Codes that can only appear once on a single W-2.
+        for code in ["DD", "W"] {
+            if self.box_12.iter().filter(|b| b.code == code).count() > 1 {
+                errors.add_error(
+                    "box_12",
+                    format!("Box 12 code {code} must not appear more than once"),
+                );
+            }
+        }
+
+        let deferral_limit =
+            rules.elective_deferral_limit() + rules.elective_deferral_catch_up_limit();
+        if self.elective_deferrals() > deferral_limit {
+            errors.add_warning(
+                "box_12",
+                format!(
+                    "Elective deferrals {} exceed the {} § 402(g) limit of {} (including the maximum catch-up allowance)",
+                    self.elective_deferrals(),
+                    self.tax_year,
+                    deferral_limit
+                ),
+            );
+        }
+
+        self.check_payroll_tax_consistency(rules, tolerance, &mut errors);
+
+        errors
+    }
+
+    /// Cross-checks Box 3/4/5/6/7 against statutory Social Security and
+    /// Medicare rates, adding a warning-level entry for each inconsistency.
+    /// These catch data-entry and OCR errors; they're warnings, not errors,
+    /// since a return can still be calculated from whichever box is correct.
+    fn check_payroll_tax_consistency(
+        &self,
+        rules: &dyn TaxRules,
+        tolerance: Money,
+        errors: &mut ValidationErrors,
+    ) {
+        let wage_base = rules.social_security_wage_base();
+        let ss_rate = rules.social_security_rate();
+        let medicare_rate = rules.medicare_rate();
+        let additional_medicare_rate = rules.additional_medicare_rate();
+        // Employers withhold Additional Medicare Tax once an individual
+        // employer's wages exceed $200,000, regardless of the employee's
+        // actual filing status.
+        let additional_medicare_threshold =
+            rules.additional_medicare_threshold(FilingStatus::Single);
+
+        let ss_wages_and_tips = self.box_3_social_security_wages + self.box_7_social_security_tips;
+        if ss_wages_and_tips > wage_base {
+            errors.add_warning(
+                "box_3_social_security_wages",
+                format!(
+                    "Social Security wages plus tips {} exceed the {} wage base of {}",
+                    ss_wages_and_tips, self.tax_year, wage_base
+                ),
+            );
+        }
+
+        let expected_ss_tax = ss_wages_and_tips.min(wage_base).multiply_rate(ss_rate);
+        if (self.box_4_social_security_tax_withheld - expected_ss_tax).abs() > tolerance {
+            errors.add_warning(
+                "box_4_social_security_tax_withheld",
+                format!(
+                    "Box 4 ({}) does not match the expected Social Security tax of {}, within a tolerance of {}",
+                    self.box_4_social_security_tax_withheld, expected_ss_tax, tolerance
+                ),
+            );
+        }
+
+        let medicare_excess = self
+            .box_5_medicare_wages
+            .saturating_sub(additional_medicare_threshold);
+        let expected_medicare_tax = self.box_5_medicare_wages.multiply_rate(medicare_rate)
+            + medicare_excess.multiply_rate(additional_medicare_rate);
+        if (self.box_6_medicare_tax_withheld - expected_medicare_tax).abs() > tolerance {
+            errors.add_warning(
+                "box_6_medicare_tax_withheld",
+                format!(
+                    "Box 6 ({}) does not match the expected Medicare tax of {} ({} plus Additional Medicare Tax above {}), within a tolerance of {}",
+                    self.box_6_medicare_tax_withheld,
+                    expected_medicare_tax,
+                    medicare_rate,
+                    additional_medicare_threshold,
+                    tolerance
+                ),
+            );
+        }
+
+        if self.box_5_medicare_wages < self.box_3_social_security_wages {
+            errors.add_warning(
+                "box_5_medicare_wages",
+                format!(
+                    "Medicare wages ({}) are less than Social Security wages ({}); Medicare has no wage cap",
+                    self.box_5_medicare_wages, self.box_3_social_security_wages
+                ),
+            );
+        }
     }
 
     /// Returns true if the SSN format is valid (XXX-XX-XXXX).
@@ -308,6 +476,33 @@ impl W2 {
             .map(|b| b.amount)
     }
 
+    /// Sums Box 12 amounts in a given category.
+    fn box_12_total(&self, category: Box12Category) -> Money {
+        self.box_12
+            .iter()
+            .filter(|b| b.category() == category)
+            .map(|b| b.amount)
+            .sum()
+    }
+
+    /// Total elective deferrals to employer retirement plans (Box 12 codes
+    /// D/E/F/G/H/S/AA/BB/EE), subject to the § 402(g) annual limit.
+    pub fn elective_deferrals(&self) -> Money {
+        self.box_12_total(Box12Category::ElectiveDeferral)
+    }
+
+    /// Total employee HSA contributions through a cafeteria plan (Box 12
+    /// code W).
+    pub fn hsa_contributions(&self) -> Money {
+        self.box_12_total(Box12Category::HsaContribution)
+    }
+
+    /// Cost of employer-sponsored health coverage (Box 12 code DD);
+    /// informational only, not included in income.
+    pub fn employer_health_coverage(&self) -> Money {
+        self.box_12_total(Box12Category::EmployerHealthCoverage)
+    }
+
     /// Returns true if this is a statutory employee.
     pub fn is_statutory_employee(&self) -> bool {
         self.box_13_statutory_employee
@@ -317,6 +512,82 @@ impl W2 {
     pub fn has_retirement_plan(&self) -> bool {
         self.box_13_retirement_plan
     }
+
+    /// Returns the number of distinct employers (by EIN) across a set of W-2s.
+    fn distinct_employer_count(forms: &[W2]) -> usize {
+        forms
+            .iter()
+            .map(|w2| w2.employer_ein.as_str())
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// Schedule 3 refundable credit for excess Social Security tax withheld
+    /// when a taxpayer worked for two or more employers.
+    ///
+    /// Only creditable when `forms` spans two or more distinct `employer_ein`
+    /// values — a single employer that over-withheld must be recovered from
+    /// that employer directly, not claimed as a credit. Returns zero rather
+    /// than an error when withholding is within the per-taxpayer cap.
+    pub fn excess_social_security_credit(forms: &[W2], rules: &dyn TaxRules) -> Money {
+        if Self::distinct_employer_count(forms) < 2 {
+            return Money::ZERO;
+        }
+
+        let total_withheld: Money = forms
+            .iter()
+            .map(|w2| w2.box_4_social_security_tax_withheld)
+            .sum();
+        let cap = rules
+            .social_security_wage_base()
+            .multiply_rate(rules.social_security_rate());
+
+        total_withheld.saturating_sub(cap)
+    }
+
+    /// Analogous Tier 1 RRTA refundable credit for railroad employees who
+    /// worked for two or more employers, mirroring
+    /// [`W2::excess_social_security_credit`].
+    pub fn excess_rrta_tier1_credit(forms: &[W2], rules: &dyn TaxRules) -> Money {
+        if Self::distinct_employer_count(forms) < 2 {
+            return Money::ZERO;
+        }
+
+        let total_withheld: Money = forms
+            .iter()
+            .map(|w2| w2.box_14_rrta_tier1_tax_withheld)
+            .sum();
+        let cap = rules
+            .rrta_tier_1_wage_base()
+            .multiply_rate(rules.rrta_tier_1_rate());
+
+        total_withheld.saturating_sub(cap)
+    }
+
+    /// Flags any single W-2 whose Box 4 Social Security tax withheld alone
+    /// exceeds the per-employer cap, since that indicates an employer
+    /// withholding error rather than a creditable excess recoverable via
+    /// [`W2::excess_social_security_credit`].
+    pub fn flag_overwithheld_employers(forms: &[W2], rules: &dyn TaxRules) -> ValidationErrors {
+        let mut errors = ValidationErrors::new();
+        let cap = rules
+            .social_security_wage_base()
+            .multiply_rate(rules.social_security_rate());
+
+        for w2 in forms {
+            if w2.box_4_social_security_tax_withheld > cap {
+                errors.add_error(
+                    format!("{}.box_4_social_security_tax_withheld", w2.id),
+                    format!(
+                        "Social Security tax withheld {} exceeds the per-employer cap of {}; this must be recovered from the employer, not claimed as a credit",
+                        w2.box_4_social_security_tax_withheld, cap
+                    ),
+                );
+            }
+        }
+
+        errors
+    }
 }
 
 impl InputForm for W2 {
@@ -358,6 +629,13 @@ impl InputForm for W2 {
         }
     }
 
+    fn state_withholding_by_state(&self) -> Vec<(String, Money)> {
+        self.state_info
+            .iter()
+            .map(|s| (s.state.clone(), s.state_tax_withheld))
+            .collect()
+    }
+
     fn social_security_wages(&self) -> Option<Money> {
         Some(self.box_3_social_security_wages)
     }
@@ -386,6 +664,7 @@ impl InputForm for W2 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     fn sample_w2() -> W2 {
         W2 {
@@ -410,6 +689,7 @@ mod tests {
             box_13_statutory_employee: false,
             box_13_retirement_plan: true,
             box_13_third_party_sick_pay: false,
+            box_14_rrta_tier1_tax_withheld: Money::ZERO,
             state_info: vec![W2StateInfo {
                 state: "CA".to_string(),
                 employer_state_id: "123456789".to_string(),
@@ -422,22 +702,129 @@ mod tests {
 
     #[test]
     fn test_w2_validation_valid() {
+        let rules = crate::rules::Rules2025::new();
         let w2 = sample_w2();
-        assert!(w2.validate().is_ok());
+        assert!(w2.validate(&rules).is_ok());
     }
 
     #[test]
     fn test_w2_validation_invalid_ssn() {
+        let rules = crate::rules::Rules2025::new();
         let mut w2 = sample_w2();
         w2.employee_ssn = "invalid".to_string();
-        assert!(w2.validate().is_err());
+        assert!(w2.validate(&rules).is_err());
     }
 
     #[test]
     fn test_w2_validation_invalid_ein() {
+        let rules = crate::rules::Rules2025::new();
         let mut w2 = sample_w2();
         w2.employer_ein = "invalid".to_string();
-        assert!(w2.validate().is_err());
+        assert!(w2.validate(&rules).is_err());
+    }
+
+    #[test]
+    fn test_w2_validation_flags_inconsistent_social_security_withholding() {
+        let rules = crate::rules::Rules2025::new();
+        let mut w2 = sample_w2();
+        w2.box_4_social_security_tax_withheld = Money::from_dollars(1);
+
+        // A payroll-tax mismatch is a warning, not an error: validate() still passes.
+        assert!(w2.validate(&rules).is_ok());
+        assert!(w2
+            .validation_errors(&rules, Money::from_cents(100))
+            .has_warnings());
+    }
+
+    #[test]
+    fn test_w2_validation_medicare_wages_below_social_security_wages_is_flagged() {
+        let rules = crate::rules::Rules2025::new();
+        let mut w2 = sample_w2();
+        w2.box_5_medicare_wages = Money::from_dollars(1_000);
+        w2.box_6_medicare_tax_withheld = w2.box_5_medicare_wages.multiply_rate(dec!(0.0145));
+
+        assert!(w2
+            .validation_errors(&rules, Money::from_cents(100))
+            .has_warnings());
+    }
+
+    #[test]
+    fn test_w2_validation_within_tolerance_has_no_warnings() {
+        let rules = crate::rules::Rules2025::new();
+        let w2 = sample_w2();
+
+        assert!(!w2
+            .validation_errors(&rules, Money::from_cents(100))
+            .has_warnings());
+    }
+
+    #[test]
+    fn test_box_12_category_classifies_known_codes() {
+        assert_eq!(Box12Category::for_code("D"), Box12Category::ElectiveDeferral);
+        assert_eq!(Box12Category::for_code("AA"), Box12Category::ElectiveDeferral);
+        assert_eq!(Box12Category::for_code("W"), Box12Category::HsaContribution);
+        assert_eq!(Box12Category::for_code("DD"), Box12Category::EmployerHealthCoverage);
+        assert_eq!(Box12Category::for_code("A"), Box12Category::UncollectedTax);
+        assert_eq!(Box12Category::for_code("C"), Box12Category::Other);
+    }
+
+    #[test]
+    fn test_elective_deferrals_and_hsa_contributions_sum_by_category() {
+        let mut w2 = sample_w2();
+        w2.box_12 = vec![
+            W2Box12 {
+                code: "D".to_string(),
+                amount: Money::from_dollars(10_000),
+            },
+            W2Box12 {
+                code: "AA".to_string(),
+                amount: Money::from_dollars(2_000),
+            },
+            W2Box12 {
+                code: "W".to_string(),
+                amount: Money::from_dollars(3_000),
+            },
+            W2Box12 {
+                code: "DD".to_string(),
+                amount: Money::from_dollars(8_000),
+            },
+        ];
+
+        assert_eq!(w2.elective_deferrals(), Money::from_dollars(12_000));
+        assert_eq!(w2.hsa_contributions(), Money::from_dollars(3_000));
+        assert_eq!(w2.employer_health_coverage(), Money::from_dollars(8_000));
+    }
+
+    #[test]
+    fn test_validate_flags_elective_deferrals_over_402g_limit() {
+        let rules = crate::rules::Rules2025::new();
+        let mut w2 = sample_w2();
+        w2.box_12 = vec![W2Box12 {
+            code: "D".to_string(),
+            amount: Money::from_dollars(40_000),
+        }];
+
+        assert!(w2
+            .validation_errors(&rules, Money::from_cents(100))
+            .has_warnings());
+    }
+
+    #[test]
+    fn test_validate_flags_duplicate_dd_code() {
+        let rules = crate::rules::Rules2025::new();
+        let mut w2 = sample_w2();
+        w2.box_12 = vec![
+            W2Box12 {
+                code: "DD".to_string(),
+                amount: Money::from_dollars(8_000),
+            },
+            W2Box12 {
+                code: "DD".to_string(),
+                amount: Money::from_dollars(1_000),
+            },
+        ];
+
+        assert!(w2.validate(&rules).is_err());
     }
 
     #[test]
@@ -455,4 +842,86 @@ mod tests {
         assert_eq!(w2.tax_year, 2025);
         assert_eq!(w2.box_1_wages, Money::ZERO);
     }
+
+    #[test]
+    fn test_excess_social_security_credit_requires_two_employers() {
+        let rules = crate::rules::Rules2025::new();
+        let mut w2 = sample_w2();
+        w2.box_4_social_security_tax_withheld = Money::from_dollars(12_000);
+
+        assert_eq!(
+            W2::excess_social_security_credit(&[w2], &rules),
+            Money::ZERO
+        );
+    }
+
+    #[test]
+    fn test_excess_social_security_credit_across_two_employers() {
+        let rules = crate::rules::Rules2025::new();
+        let mut first = sample_w2();
+        first.box_4_social_security_tax_withheld = Money::from_dollars(6_000);
+        let mut second = sample_w2();
+        second.employer_ein = "98-7654321".to_string();
+        second.box_4_social_security_tax_withheld = Money::from_dollars(6_000);
+
+        // Cap for 2025 is $176,100 * 6.2% = $10,918.20; total withheld is $12,000.
+        assert_eq!(
+            W2::excess_social_security_credit(&[first, second], &rules),
+            Money::from_cents(108_180)
+        );
+    }
+
+    #[test]
+    fn test_flag_overwithheld_employers_flags_single_employer_error() {
+        let rules = crate::rules::Rules2025::new();
+        let mut w2 = sample_w2();
+        w2.box_4_social_security_tax_withheld = Money::from_dollars(12_000);
+
+        let errors = W2::flag_overwithheld_employers(&[w2], &rules);
+        assert!(errors.has_errors());
+    }
+
+    #[test]
+    fn test_flag_overwithheld_employers_passes_when_within_cap() {
+        let rules = crate::rules::Rules2025::new();
+        let errors = W2::flag_overwithheld_employers(&[sample_w2()], &rules);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_state_withholding_by_state_partitions_multi_state_w2() {
+        let mut w2 = sample_w2();
+        w2.state_info.push(W2StateInfo {
+            state: "AZ".to_string(),
+            employer_state_id: "987654321".to_string(),
+            state_wages: Money::from_dollars(10_000),
+            state_tax_withheld: Money::from_dollars(250),
+        });
+
+        let by_state = w2.state_withholding_by_state();
+        assert_eq!(
+            by_state,
+            vec![
+                ("CA".to_string(), Money::from_dollars(5_000)),
+                ("AZ".to_string(), Money::from_dollars(250)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_total_state_withholding_by_state_sums_across_forms() {
+        use crate::traits::{InputForm, InputFormCollection};
+
+        let mut second = sample_w2();
+        second.state_info = vec![W2StateInfo {
+            state: "CA".to_string(),
+            employer_state_id: "123456789".to_string(),
+            state_wages: Money::from_dollars(20_000),
+            state_tax_withheld: Money::from_dollars(1_000),
+        }];
+
+        let forms: Vec<Box<dyn InputForm>> = vec![Box::new(sample_w2()), Box::new(second)];
+        let totals = forms.total_state_withholding_by_state();
+        assert_eq!(totals.get("CA"), Some(&Money::from_dollars(6_000)));
+    }
 }
@@ -1,9 +1,96 @@
 //! Form 1040: U.S. Individual Income Tax Return
 
+use crate::error::ValidationSeverity;
+use crate::form8606::Form8606Result;
 use crate::money::Money;
-use crate::traits::{FormLine, FormValue, OutputForm};
-use crate::types::{OutputFormType, TaxYear};
+use crate::traits::{FormLine, FormValue, OutputForm, TaxRules};
+use crate::types::{FilingStatus, OutputFormType, TaxYear};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// One step of a progressive tax bracket table, in the same shape as the
+/// IRS Tax Computation Worksheet: a flat amount owed for reaching this
+/// bracket, plus a marginal rate applied to the income above it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TaxBracketStep {
+    /// Upper limit of this bracket; `None` marks the top (unbounded) bracket.
+    pub upper_limit: Option<Money>,
+    /// Flat tax amount owed for income up to this bracket's lower limit.
+    pub flat_amount: Money,
+    /// Marginal rate applied to the income above this bracket's lower limit.
+    pub marginal_rate: Decimal,
+}
+
+/// An ordered, per-filing-status tax bracket table used to derive Line 16
+/// from Line 15 (taxable income).
+///
+/// Steps must be ordered by ascending `upper_limit`, with the last step's
+/// `upper_limit` set to `None`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TaxBrackets {
+    steps: Vec<TaxBracketStep>,
+}
+
+impl TaxBrackets {
+    /// Creates a tax bracket table from its ordered steps.
+    pub fn new(steps: Vec<TaxBracketStep>) -> Self {
+        Self { steps }
+    }
+
+    /// Computes tax owed on `taxable_income` by walking the bracket steps
+    /// to the first one whose `upper_limit` is `None` or `>= taxable_income`,
+    /// then applying that step's flat amount plus its marginal rate on the
+    /// income above its lower limit. Rounded to the nearest dollar per IRS
+    /// Tax Computation Worksheet rules.
+    pub fn tax_for(&self, taxable_income: Money) -> Money {
+        let mut lower_limit = Money::ZERO;
+        for step in &self.steps {
+            let reached = match step.upper_limit {
+                Some(upper) => taxable_income <= upper,
+                None => true,
+            };
+            if reached {
+                let excess = taxable_income.saturating_sub(lower_limit);
+                return (step.flat_amount + excess.multiply_rate(step.marginal_rate))
+                    .round_to_dollar();
+            }
+            lower_limit = step.upper_limit.unwrap_or(lower_limit);
+        }
+        Money::ZERO
+    }
+}
+
+/// A single return-level validation finding from [`Form1040::validate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    /// Severity of the finding.
+    pub severity: ValidationSeverity,
+    /// The line(s) this finding is about.
+    pub line_ids: Vec<String>,
+    /// Human-readable description of the problem.
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn error(line_ids: impl IntoIterator<Item = impl Into<String>>, message: impl Into<String>) -> Self {
+        Self {
+            severity: ValidationSeverity::Error,
+            line_ids: line_ids.into_iter().map(Into::into).collect(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Line IDs recognized by [`Form1040::line`], used to sweep for negative
+/// currency values in [`Form1040::validate`].
+const ALL_LINE_IDS: &[&str] = &[
+    "1a", "1b", "1c", "1d", "1e", "1f", "1g", "1h", "1i", "1z", "2a", "2b", "3a", "3b", "4a", "4b",
+    "5a", "5b", "6a", "6b", "7", "8", "9", "10", "11", "12", "13a", "13b", "14", "15", "16", "17",
+    "18", "19", "20", "21", "22", "23", "24", "25a", "25b", "25c", "25d", "26", "27a", "28", "29",
+    "30", "31", "32", "33", "34", "35a", "36", "37", "38",
+];
 
 /// Form 1040: U.S. Individual Income Tax Return
 ///
@@ -13,6 +100,22 @@ pub struct Form1040 {
     /// Tax year this form is for.
     pub tax_year: TaxYear,
 
+    /// Filing status claimed on the return. Drives the standard deduction
+    /// (Line 12) and the bracket table used to compute Line 16.
+    pub filing_status: FilingStatus,
+
+    /// Whether the taxpayer is age 65 or older at year end.
+    pub taxpayer_65_or_older: bool,
+
+    /// Whether the taxpayer is blind.
+    pub taxpayer_blind: bool,
+
+    /// Whether the spouse is age 65 or older at year end (MFJ only).
+    pub spouse_65_or_older: bool,
+
+    /// Whether the spouse is blind (MFJ only).
+    pub spouse_blind: bool,
+
     // ─────────────────────────────────────────────────────────────────────────
     // Income (Lines 1-9)
     // ─────────────────────────────────────────────────────────────────────────
@@ -211,6 +314,11 @@ impl Form1040 {
     pub fn new(tax_year: TaxYear) -> Self {
         Self {
             tax_year,
+            filing_status: FilingStatus::Single,
+            taxpayer_65_or_older: false,
+            taxpayer_blind: false,
+            spouse_65_or_older: false,
+            spouse_blind: false,
             line_1a: Money::ZERO,
             line_1b: Money::ZERO,
             line_1c: Money::ZERO,
@@ -300,6 +408,91 @@ impl Form1040 {
         self.line_11 = self.line_9.saturating_sub(self.line_10);
     }
 
+    /// Calculates line 12 (standard deduction) when the standard-deduction
+    /// checkbox is set, looking up the base amount for
+    /// `(tax_year, filing_status)` plus age/blindness add-ons. Leaves
+    /// `line_12` untouched when itemizing, since that amount comes from
+    /// Schedule A instead.
+    pub fn calculate_line_12(&mut self, rules: &dyn TaxRules) {
+        if self.line_12_standard_deduction {
+            self.line_12 = rules.standard_deduction(
+                self.filing_status,
+                self.taxpayer_65_or_older,
+                self.taxpayer_blind,
+                self.spouse_65_or_older,
+                self.spouse_blind,
+                self.line_11,
+            );
+        }
+    }
+
+    /// Returns the (base threshold, second threshold, 85%-tier cap) for the
+    /// taxable Social Security worksheet, keyed on filing status.
+    ///
+    /// These thresholds are fixed by statute (not inflation-indexed).
+    /// Married filing separately is treated like single, assuming the
+    /// taxpayer lived apart from their spouse all year; the zero-threshold
+    /// case for spouses who lived together is not modeled here.
+    fn ss_worksheet_thresholds(status: FilingStatus) -> (Money, Money, Money) {
+        match status {
+            FilingStatus::MarriedFilingJointly => (
+                Money::from_dollars(32_000),
+                Money::from_dollars(44_000),
+                Money::from_dollars(6_000),
+            ),
+            _ => (
+                Money::from_dollars(25_000),
+                Money::from_dollars(34_000),
+                Money::from_dollars(4_500),
+            ),
+        }
+    }
+
+    /// Calculates line 6b (taxable Social Security benefits) from line 6a
+    /// using the IRS taxable Social Security worksheet: provisional income
+    /// is other income (excluding Social Security) plus tax-exempt
+    /// interest plus half of benefits; taxable benefits are zero below the
+    /// base threshold, the lesser of 50% of benefits or 50% of the excess
+    /// over the base between the two thresholds, and above the second
+    /// threshold the lesser of 85% of benefits or 85% of the excess over
+    /// the second threshold plus the smaller of the 50%-tier amount or a
+    /// fixed cap.
+    pub fn calculate_line_6b(&mut self) {
+        let (base, second, cap) = Self::ss_worksheet_thresholds(self.filing_status);
+
+        let income_excluding_ss = (self.line_1z
+            + self.line_2b
+            + self.line_3b
+            + self.line_4b
+            + self.line_5b
+            + self.line_7
+            + self.line_8)
+            .saturating_sub(self.line_10);
+
+        let half_benefits = self.line_6a.multiply_rate(dec!(0.5));
+        let provisional = income_excluding_ss + self.line_2a + half_benefits;
+        let eighty_five_pct_of_benefits = self.line_6a.multiply_rate(dec!(0.85));
+
+        self.line_6b = if provisional <= base {
+            Money::ZERO
+        } else if provisional <= second {
+            half_benefits.min(provisional.saturating_sub(base).multiply_rate(dec!(0.5)))
+        } else {
+            let fifty_pct_tier =
+                half_benefits.min(second.saturating_sub(base).multiply_rate(dec!(0.5)));
+            let candidate = provisional.saturating_sub(second).multiply_rate(dec!(0.85))
+                + fifty_pct_tier.min(cap);
+            eighty_five_pct_of_benefits.min(candidate)
+        };
+    }
+
+    /// Applies a Form 8606 basis-tracking result to lines 4a (gross IRA
+    /// distributions) and 4b (taxable IRA distributions).
+    pub fn apply_form_8606(&mut self, result: &Form8606Result) {
+        self.line_4a = result.gross_distribution;
+        self.line_4b = result.taxable_distribution;
+    }
+
     /// Calculates line 14 (total deductions).
     pub fn calculate_line_14(&mut self) {
         self.line_14 = self.line_12 + self.line_13a + self.line_13b;
@@ -310,6 +503,26 @@ impl Form1040 {
         self.line_15 = self.line_11.saturating_sub(self.line_14);
     }
 
+    /// Calculates line 16 (tax) from line 15 (taxable income) using the
+    /// progressive bracket table for `self.filing_status`, for filers
+    /// computing tax via the Tax Computation Worksheet rather than looking
+    /// it up in the Tax Table. Mirrors real calculators' filing-status
+    /// branching: married filing jointly, for example, gets its own table.
+    ///
+    /// Panics if `brackets_by_status` has no entry for `self.filing_status`:
+    /// that's a caller bug (an incomplete bracket table), and silently
+    /// leaving line 16 at zero would produce a return claiming no tax is
+    /// owed on positive taxable income.
+    pub fn calculate_line_16(&mut self, brackets_by_status: &HashMap<FilingStatus, TaxBrackets>) {
+        let brackets = brackets_by_status.get(&self.filing_status).unwrap_or_else(|| {
+            panic!(
+                "no tax brackets provided for filing status {:?}; line 16 cannot be computed",
+                self.filing_status
+            )
+        });
+        self.line_16 = brackets.tax_for(self.line_15);
+    }
+
     /// Calculates line 18 (total tax before credits).
     pub fn calculate_line_18(&mut self) {
         self.line_18 = self.line_16 + self.line_17;
@@ -359,6 +572,147 @@ impl Form1040 {
         }
     }
 
+    /// Runs every derived-line calculation in dependency order: wages →
+    /// total income → AGI → deductions → taxable income → tax → credits →
+    /// payments → refund/owed. This is the one entry point callers should
+    /// use instead of invoking the individual `calculate_*` helpers by
+    /// hand, since forgetting one silently leaves stale totals downstream.
+    ///
+    /// Each step declares the lines it depends on; in debug builds, this
+    /// asserts that every dependency was already computed earlier in the
+    /// pass.
+    pub fn recalculate(
+        &mut self,
+        rules: &dyn TaxRules,
+        brackets_by_status: &HashMap<FilingStatus, TaxBrackets>,
+    ) {
+        let mut computed: HashSet<&'static str> = HashSet::new();
+
+        macro_rules! step {
+            ($deps:expr, $produces:expr, $call:expr) => {{
+                for dep in $deps {
+                    debug_assert!(
+                        computed.contains(dep),
+                        "line {} computed before its dependency {}",
+                        $produces,
+                        dep
+                    );
+                }
+                $call;
+                computed.insert($produces);
+            }};
+        }
+
+        step!([], "1z", self.calculate_line_1z());
+        step!(["1z"], "6b", self.calculate_line_6b());
+        step!(["1z", "6b"], "9", self.calculate_line_9());
+        step!(["9"], "11", self.calculate_line_11());
+        step!(["11"], "12", self.calculate_line_12(rules));
+        step!(["12"], "14", self.calculate_line_14());
+        step!(["11", "14"], "15", self.calculate_line_15());
+        step!(["15"], "16", self.calculate_line_16(brackets_by_status));
+        step!(["16"], "18", self.calculate_line_18());
+        step!(["18"], "21", self.calculate_line_21());
+        step!(["18", "21"], "22", self.calculate_line_22());
+        step!(["22"], "24", self.calculate_line_24());
+        step!([], "25d", self.calculate_line_25d());
+        step!([], "32", self.calculate_line_32());
+        step!(["25d", "32"], "33", self.calculate_line_33());
+        step!(["24", "33"], "refund", self.calculate_refund_or_owed());
+    }
+
+    /// Checks cross-line invariants and returns machine-readable findings
+    /// rather than panicking, so callers can surface every problem with a
+    /// return before filing.
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let expected_1z = self.line_1a
+            + self.line_1b
+            + self.line_1c
+            + self.line_1d
+            + self.line_1e
+            + self.line_1f
+            + self.line_1g
+            + self.line_1h;
+        if self.line_1z != expected_1z {
+            diagnostics.push(Diagnostic::error(
+                ["1z"],
+                format!(
+                    "line 1z ({}) does not equal the sum of lines 1a-1h ({})",
+                    self.line_1z.as_decimal(),
+                    expected_1z.as_decimal()
+                ),
+            ));
+        }
+
+        let expected_9 = self.line_1z
+            + self.line_2b
+            + self.line_3b
+            + self.line_4b
+            + self.line_5b
+            + self.line_6b
+            + self.line_7
+            + self.line_8;
+        if self.line_9 != expected_9 {
+            diagnostics.push(Diagnostic::error(
+                ["9"],
+                format!(
+                    "line 9 ({}) does not equal the sum of its components ({})",
+                    self.line_9.as_decimal(),
+                    expected_9.as_decimal()
+                ),
+            ));
+        }
+
+        let has_refund = self.line_34.is_positive() || self.line_35a.is_positive();
+        let has_amount_owed = self.line_37.is_positive();
+        if has_refund && has_amount_owed {
+            diagnostics.push(Diagnostic::error(
+                ["34", "35a", "37"],
+                "a return cannot show both a refund and an amount owed",
+            ));
+        }
+
+        if self.line_3a > self.line_3b {
+            diagnostics.push(Diagnostic::error(
+                ["3a", "3b"],
+                "qualified dividends (3a) cannot exceed ordinary dividends (3b)",
+            ));
+        }
+        if self.line_4b > self.line_4a {
+            diagnostics.push(Diagnostic::error(
+                ["4a", "4b"],
+                "taxable IRA distributions (4b) cannot exceed gross IRA distributions (4a)",
+            ));
+        }
+        if self.line_5b > self.line_5a {
+            diagnostics.push(Diagnostic::error(
+                ["5a", "5b"],
+                "taxable pensions (5b) cannot exceed gross pensions (5a)",
+            ));
+        }
+        if self.line_6b > self.line_6a {
+            diagnostics.push(Diagnostic::error(
+                ["6a", "6b"],
+                "taxable Social Security (6b) cannot exceed gross Social Security benefits (6a)",
+            ));
+        }
+
+        for line_id in ALL_LINE_IDS {
+            if let Some(FormValue::Currency(value)) = self.line(line_id) {
+                if value.is_negative() {
+                    diagnostics.push(Diagnostic::error(
+                        [*line_id],
+                        format!("line {line_id} must not be negative, got {}", value.as_decimal()),
+                    ));
+                }
+            }
+        }
+
+        diagnostics
+    }
+
     /// Returns true if the taxpayer is getting a refund.
     pub fn is_refund(&self) -> bool {
         self.line_34.is_positive()
@@ -540,3 +894,248 @@ impl OutputForm for Form1040 {
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn single_2025_brackets() -> TaxBrackets {
+        TaxBrackets::new(vec![
+            TaxBracketStep {
+                upper_limit: Some(Money::from_dollars(11_925)),
+                flat_amount: Money::ZERO,
+                marginal_rate: dec!(0.10),
+            },
+            TaxBracketStep {
+                upper_limit: Some(Money::from_dollars(48_475)),
+                flat_amount: Money::from_dollars(1_192),
+                marginal_rate: dec!(0.12),
+            },
+            TaxBracketStep {
+                upper_limit: None,
+                flat_amount: Money::from_dollars(5_578),
+                marginal_rate: dec!(0.22),
+            },
+        ])
+    }
+
+    #[test]
+    fn test_tax_for_first_bracket() {
+        let brackets = single_2025_brackets();
+        assert_eq!(
+            brackets.tax_for(Money::from_dollars(10_000)),
+            Money::from_dollars(1_000)
+        );
+    }
+
+    #[test]
+    fn test_tax_for_middle_bracket() {
+        let brackets = single_2025_brackets();
+        let tax = brackets.tax_for(Money::from_dollars(20_000));
+        assert_eq!(
+            tax,
+            (Money::from_dollars(1_192) + Money::from_dollars(20_000 - 11_925).multiply_rate(dec!(0.12)))
+                .round_to_dollar()
+        );
+    }
+
+    #[test]
+    fn test_tax_for_top_bracket_has_no_upper_limit() {
+        let brackets = single_2025_brackets();
+        let tax = brackets.tax_for(Money::from_dollars(100_000));
+        assert_eq!(
+            tax,
+            (Money::from_dollars(5_578) + Money::from_dollars(100_000 - 48_475).multiply_rate(dec!(0.22)))
+                .round_to_dollar()
+        );
+    }
+
+    #[test]
+    fn test_calculate_line_16_derives_from_line_15() {
+        let mut form = Form1040::new(2025);
+        form.filing_status = FilingStatus::Single;
+        form.line_15 = Money::from_dollars(10_000);
+        let mut brackets_by_status = HashMap::new();
+        brackets_by_status.insert(FilingStatus::Single, single_2025_brackets());
+        form.calculate_line_16(&brackets_by_status);
+        assert_eq!(form.line_16, Money::from_dollars(1_000));
+    }
+
+    #[test]
+    #[should_panic(expected = "no tax brackets provided for filing status")]
+    fn test_calculate_line_16_missing_status_panics() {
+        let mut form = Form1040::new(2025);
+        form.filing_status = FilingStatus::MarriedFilingJointly;
+        form.line_15 = Money::from_dollars(10_000);
+        let mut brackets_by_status = HashMap::new();
+        brackets_by_status.insert(FilingStatus::Single, single_2025_brackets());
+        form.calculate_line_16(&brackets_by_status);
+    }
+
+    #[test]
+    fn test_calculate_line_12_standard_deduction() {
+        use crate::rules::Rules2025;
+
+        let mut form = Form1040::new(2025);
+        form.filing_status = FilingStatus::Single;
+        form.line_11 = Money::from_dollars(60_000);
+
+        let rules = Rules2025::new();
+        form.calculate_line_12(&rules);
+        assert_eq!(
+            form.line_12,
+            rules.standard_deduction(
+                FilingStatus::Single,
+                false,
+                false,
+                false,
+                false,
+                Money::from_dollars(60_000),
+            )
+        );
+    }
+
+    #[test]
+    fn test_recalculate_produces_consistent_totals() {
+        use crate::rules::Rules2025;
+
+        let mut form = Form1040::new(2025);
+        form.filing_status = FilingStatus::Single;
+        form.line_1a = Money::from_dollars(60_000);
+        form.line_25a = Money::from_dollars(5_000);
+
+        let rules = Rules2025::new();
+        let mut brackets_by_status = HashMap::new();
+        brackets_by_status.insert(FilingStatus::Single, single_2025_brackets());
+
+        form.recalculate(&rules, &brackets_by_status);
+
+        assert_eq!(form.line_1z, Money::from_dollars(60_000));
+        assert_eq!(form.line_9, Money::from_dollars(60_000));
+        assert_eq!(form.line_11, Money::from_dollars(60_000));
+        assert_eq!(
+            form.line_12,
+            rules.standard_deduction(
+                FilingStatus::Single,
+                false,
+                false,
+                false,
+                false,
+                Money::from_dollars(60_000),
+            )
+        );
+        assert_eq!(form.line_15, form.line_11.saturating_sub(form.line_14));
+        assert_eq!(form.line_16, single_2025_brackets().tax_for(form.line_15));
+        assert_eq!(form.line_24, form.line_22 + form.line_23);
+        assert_eq!(form.line_33, form.line_25d + form.line_26 + form.line_32);
+    }
+
+    #[test]
+    fn test_calculate_line_6b_below_base_threshold_is_zero() {
+        let mut form = Form1040::new(2025);
+        form.filing_status = FilingStatus::Single;
+        form.line_1z = Money::from_dollars(10_000);
+        form.line_6a = Money::from_dollars(12_000);
+        form.calculate_line_6b();
+        assert_eq!(form.line_6b, Money::ZERO);
+    }
+
+    #[test]
+    fn test_calculate_line_6b_middle_tier() {
+        // provisional = 20,000 + 0.5*12,000 = 26,000; base=25,000, second=34,000.
+        // taxable = min(0.5*12,000, 0.5*(26,000-25,000)) = min(6,000, 500) = 500.
+        let mut form = Form1040::new(2025);
+        form.filing_status = FilingStatus::Single;
+        form.line_1z = Money::from_dollars(20_000);
+        form.line_6a = Money::from_dollars(12_000);
+        form.calculate_line_6b();
+        assert_eq!(form.line_6b, Money::from_dollars(500));
+    }
+
+    #[test]
+    fn test_calculate_line_6b_never_exceeds_85_percent_of_benefits() {
+        let mut form = Form1040::new(2025);
+        form.filing_status = FilingStatus::MarriedFilingJointly;
+        form.line_1z = Money::from_dollars(200_000);
+        form.line_6a = Money::from_dollars(20_000);
+        form.calculate_line_6b();
+        assert_eq!(form.line_6b, Money::from_dollars(20_000).multiply_rate(dec!(0.85)));
+    }
+
+    #[test]
+    fn test_validate_clean_return_has_no_diagnostics() {
+        let mut form = Form1040::new(2025);
+        form.line_1a = Money::from_dollars(60_000);
+        form.calculate_line_1z();
+        form.calculate_line_9();
+        assert!(form.validate().is_empty());
+    }
+
+    #[test]
+    fn test_validate_flags_inconsistent_line_1z() {
+        let mut form = Form1040::new(2025);
+        form.line_1a = Money::from_dollars(60_000);
+        form.line_1z = Money::from_dollars(50_000);
+        let diagnostics = form.validate();
+        assert!(diagnostics.iter().any(|d| d.line_ids == vec!["1z".to_string()]));
+    }
+
+    #[test]
+    fn test_validate_flags_refund_and_owed_both_nonzero() {
+        let mut form = Form1040::new(2025);
+        form.line_35a = Money::from_dollars(100);
+        form.line_37 = Money::from_dollars(50);
+        let diagnostics = form.validate();
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.message.contains("refund and an amount owed")));
+    }
+
+    #[test]
+    fn test_validate_flags_qualified_exceeding_ordinary_dividends() {
+        let mut form = Form1040::new(2025);
+        form.line_3a = Money::from_dollars(500);
+        form.line_3b = Money::from_dollars(300);
+        let diagnostics = form.validate();
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.line_ids == vec!["3a".to_string(), "3b".to_string()]));
+    }
+
+    #[test]
+    fn test_validate_flags_negative_line() {
+        let mut form = Form1040::new(2025);
+        form.line_10 = Money::from_dollars(-100);
+        let diagnostics = form.validate();
+        assert!(diagnostics.iter().any(|d| d.line_ids == vec!["10".to_string()]));
+    }
+
+    #[test]
+    fn test_apply_form_8606_sets_lines_4a_4b() {
+        let mut form = Form1040::new(2025);
+        form.apply_form_8606(&Form8606Result {
+            gross_distribution: Money::from_dollars(20_000),
+            taxable_distribution: Money::from_dollars(18_000),
+            nontaxable_distribution: Money::from_dollars(2_000),
+            remaining_basis: Money::from_dollars(8_000),
+        });
+        assert_eq!(form.line_4a, Money::from_dollars(20_000));
+        assert_eq!(form.line_4b, Money::from_dollars(18_000));
+    }
+
+    #[test]
+    fn test_calculate_line_12_skips_when_itemizing() {
+        use crate::rules::Rules2025;
+
+        let mut form = Form1040::new(2025);
+        form.filing_status = FilingStatus::Single;
+        form.line_11 = Money::from_dollars(60_000);
+        form.line_12_standard_deduction = false;
+        form.line_12 = Money::from_dollars(22_000);
+
+        let rules = Rules2025::new();
+        form.calculate_line_12(&rules);
+        assert_eq!(form.line_12, Money::from_dollars(22_000));
+    }
+}
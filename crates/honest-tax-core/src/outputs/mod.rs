@@ -0,0 +1,5 @@
+//! Output forms (forms generated by the calculator).
+
+mod form1040;
+
+pub use form1040::{Form1040, TaxBracketStep, TaxBrackets};
@@ -0,0 +1,348 @@
+//! Chained-CPI inflation indexing, used to synthesize `TaxRules` for years
+//! that don't have a hard-coded implementation by projecting a base year's
+//! dollar parameters forward or backward.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::money::Money;
+use crate::traits::{
+    OvertimeDeduction, PhaseOut, SeniorBonusDeduction, StudentLoanInterestDeduction, TaxBracket,
+    TaxRules, TipIncomeDeduction,
+};
+use crate::types::{FilingStatus, TaxYear};
+
+/// Rounding unit (in whole dollars) applied to indexed tax brackets.
+const BRACKET_ROUNDING_UNIT: i64 = 50;
+
+/// Rounding unit (in whole dollars) applied to the indexed standard deduction.
+const STANDARD_DEDUCTION_ROUNDING_UNIT: i64 = 25;
+
+/// Rounding unit (in whole dollars) applied to the indexed CTC phase-out thresholds.
+const PHASE_OUT_ROUNDING_UNIT: i64 = 50;
+
+/// Per-year chained-CPI growth rates, used to project dollar parameters
+/// from one tax year to another.
+///
+/// An optional `cpi_offset` is added to every year's growth rate, modeling
+/// the difference between regular CPI and chained CPI indexing.
+#[derive(Debug, Clone, Default)]
+pub struct InflationIndex {
+    /// Year-over-year growth rate to apply *for* that year (e.g., the rate
+    /// used to project from `year - 1` to `year`).
+    growth_rates: HashMap<TaxYear, Decimal>,
+    /// Delta added to every year's growth rate before compounding.
+    cpi_offset: Decimal,
+}
+
+impl InflationIndex {
+    /// Creates an index from a table of per-year growth rates.
+    pub fn new(growth_rates: HashMap<TaxYear, Decimal>) -> Self {
+        Self {
+            growth_rates,
+            cpi_offset: Decimal::ZERO,
+        }
+    }
+
+    /// Returns a copy of this index with a CPI-offset delta applied to every
+    /// year's growth rate (e.g., to model a regular-to-chained-CPI shift).
+    pub fn with_cpi_offset(mut self, offset: Decimal) -> Self {
+        self.cpi_offset = offset;
+        self
+    }
+
+    fn rate_for(&self, year: TaxYear) -> Decimal {
+        self.growth_rates.get(&year).copied().unwrap_or(Decimal::ZERO) + self.cpi_offset
+    }
+
+    /// Returns the inclusive range of years this index has explicit growth
+    /// rates for, or `None` if it's empty.
+    pub fn year_range(&self) -> Option<(TaxYear, TaxYear)> {
+        let min = self.growth_rates.keys().min().copied()?;
+        let max = self.growth_rates.keys().max().copied()?;
+        Some((min, max))
+    }
+
+    /// Returns the cumulative growth factor from `from` to `to`.
+    ///
+    /// A factor `> 1` means `to` is later than `from`; a factor `< 1` means
+    /// it's earlier. `cumulative_factor(y, y)` is always `1`.
+    pub fn cumulative_factor(&self, from: TaxYear, to: TaxYear) -> Decimal {
+        if from == to {
+            return Decimal::ONE;
+        }
+        if from < to {
+            let mut factor = Decimal::ONE;
+            for year in (from + 1)..=to {
+                factor *= Decimal::ONE + self.rate_for(year);
+            }
+            factor
+        } else {
+            Decimal::ONE / self.cumulative_factor(to, from)
+        }
+    }
+}
+
+fn round_to_unit(amount: Money, unit: i64) -> Money {
+    let unit = Decimal::new(unit, 0);
+    let units = (amount.as_decimal() / unit)
+        .round_dp_with_strategy(0, rust_decimal::RoundingStrategy::MidpointAwayFromZero);
+    Money::new(units * unit)
+}
+
+fn project(amount: Money, factor: Decimal, rounding_unit: i64) -> Money {
+    round_to_unit(Money::new(amount.as_decimal() * factor), rounding_unit)
+}
+
+/// `TaxRules` adapter that projects a base year's dollar parameters forward
+/// or backward by cumulative chained-CPI growth.
+///
+/// Only the bracket edges, the standard deduction amounts (base, age 65+,
+/// and blind), and the CTC phase-out thresholds are projected. Every other
+/// parameter passes through from the base year unchanged: flat-rate ones
+/// (e.g. `qbi_deduction_rate`, the OBBBA senior bonus amount) and also the
+/// remaining dollar amounts, such as the CTC maximum, the Social Security
+/// wage base, the elective deferral limits, and the tip, overtime, and
+/// student loan deduction caps and thresholds.
+#[derive(Debug)]
+pub struct IndexedRules {
+    base: Arc<dyn TaxRules>,
+    year: TaxYear,
+    brackets: HashMap<FilingStatus, Vec<TaxBracket>>,
+    standard_deduction_base: HashMap<FilingStatus, Money>,
+    standard_deduction_age_65: HashMap<FilingStatus, Money>,
+    standard_deduction_blind: HashMap<FilingStatus, Money>,
+    ctc_phase_out: PhaseOut,
+}
+
+impl IndexedRules {
+    /// Projects `base`'s dollar parameters from `base_year` to `year` using `index`.
+    pub fn new(base: Arc<dyn TaxRules>, year: TaxYear, base_year: TaxYear, index: &InflationIndex) -> Self {
+        let factor = index.cumulative_factor(base_year, year);
+
+        let mut brackets = HashMap::new();
+        let mut standard_deduction_base = HashMap::new();
+        let mut standard_deduction_age_65 = HashMap::new();
+        let mut standard_deduction_blind = HashMap::new();
+        for &status in FilingStatus::all() {
+            let projected = base
+                .brackets(status)
+                .iter()
+                .map(|bracket| TaxBracket {
+                    rate: bracket.rate,
+                    min: project(bracket.min, factor, BRACKET_ROUNDING_UNIT),
+                    max: bracket.max.map(|max| project(max, factor, BRACKET_ROUNDING_UNIT)),
+                })
+                .collect();
+            brackets.insert(status, projected);
+
+            standard_deduction_base.insert(
+                status,
+                project(base.standard_deduction_base(status), factor, STANDARD_DEDUCTION_ROUNDING_UNIT),
+            );
+            standard_deduction_age_65.insert(
+                status,
+                project(base.standard_deduction_age_65(status), factor, STANDARD_DEDUCTION_ROUNDING_UNIT),
+            );
+            standard_deduction_blind.insert(
+                status,
+                project(base.standard_deduction_blind(status), factor, STANDARD_DEDUCTION_ROUNDING_UNIT),
+            );
+        }
+
+        let base_phase_out = base.child_tax_credit_phase_out();
+        let ctc_phase_out = PhaseOut {
+            single_threshold: project(base_phase_out.single_threshold, factor, PHASE_OUT_ROUNDING_UNIT),
+            joint_threshold: project(base_phase_out.joint_threshold, factor, PHASE_OUT_ROUNDING_UNIT),
+            mfs_threshold: project(base_phase_out.mfs_threshold, factor, PHASE_OUT_ROUNDING_UNIT),
+            rate: base_phase_out.rate,
+        };
+
+        Self {
+            base,
+            year,
+            brackets,
+            standard_deduction_base,
+            standard_deduction_age_65,
+            standard_deduction_blind,
+            ctc_phase_out,
+        }
+    }
+}
+
+impl TaxRules for IndexedRules {
+    fn year(&self) -> TaxYear {
+        self.year
+    }
+
+    fn brackets(&self, status: FilingStatus) -> &[TaxBracket] {
+        self.brackets
+            .get(&status)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    fn standard_deduction_base(&self, status: FilingStatus) -> Money {
+        self.standard_deduction_base
+            .get(&status)
+            .copied()
+            .unwrap_or_else(|| self.base.standard_deduction_base(status))
+    }
+
+    fn standard_deduction_age_65(&self, status: FilingStatus) -> Money {
+        self.standard_deduction_age_65
+            .get(&status)
+            .copied()
+            .unwrap_or_else(|| self.base.standard_deduction_age_65(status))
+    }
+
+    fn standard_deduction_blind(&self, status: FilingStatus) -> Money {
+        self.standard_deduction_blind
+            .get(&status)
+            .copied()
+            .unwrap_or_else(|| self.base.standard_deduction_blind(status))
+    }
+
+    fn senior_bonus_deduction(&self) -> Option<SeniorBonusDeduction> {
+        self.base.senior_bonus_deduction()
+    }
+
+    fn tip_income_deduction(&self) -> Option<TipIncomeDeduction> {
+        self.base.tip_income_deduction()
+    }
+
+    fn overtime_deduction(&self) -> Option<OvertimeDeduction> {
+        self.base.overtime_deduction()
+    }
+
+    fn student_loan_interest_deduction(&self) -> Option<StudentLoanInterestDeduction> {
+        self.base.student_loan_interest_deduction()
+    }
+
+    fn child_tax_credit_max(&self) -> Money {
+        self.base.child_tax_credit_max()
+    }
+
+    fn additional_child_tax_credit_max(&self) -> Money {
+        self.base.additional_child_tax_credit_max()
+    }
+
+    fn actc_earned_income_threshold(&self) -> Money {
+        self.base.actc_earned_income_threshold()
+    }
+
+    fn child_tax_credit_phase_out(&self) -> &PhaseOut {
+        &self.ctc_phase_out
+    }
+
+    fn credit_for_other_dependents(&self) -> Money {
+        self.base.credit_for_other_dependents()
+    }
+
+    fn personal_exemption(&self) -> Money {
+        self.base.personal_exemption()
+    }
+
+    fn qbi_deduction_rate(&self) -> Decimal {
+        self.base.qbi_deduction_rate()
+    }
+
+    fn social_security_wage_base(&self) -> Money {
+        self.base.social_security_wage_base()
+    }
+
+    fn social_security_rate(&self) -> Decimal {
+        self.base.social_security_rate()
+    }
+
+    fn medicare_rate(&self) -> Decimal {
+        self.base.medicare_rate()
+    }
+
+    fn elective_deferral_limit(&self) -> Money {
+        self.base.elective_deferral_limit()
+    }
+
+    fn elective_deferral_catch_up_limit(&self) -> Money {
+        self.base.elective_deferral_catch_up_limit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Rules2025;
+
+    fn sample_index() -> InflationIndex {
+        let mut rates = HashMap::new();
+        rates.insert(2026, dec!(0.025));
+        rates.insert(2027, dec!(0.025));
+        InflationIndex::new(rates)
+    }
+
+    #[test]
+    fn test_cumulative_factor_identity() {
+        let index = sample_index();
+        assert_eq!(index.cumulative_factor(2025, 2025), Decimal::ONE);
+    }
+
+    #[test]
+    fn test_cumulative_factor_forward() {
+        let index = sample_index();
+        let factor = index.cumulative_factor(2025, 2027);
+        assert_eq!(factor, dec!(1.025) * dec!(1.025));
+    }
+
+    #[test]
+    fn test_cumulative_factor_backward_is_inverse_of_forward() {
+        let index = sample_index();
+        let forward = index.cumulative_factor(2025, 2027);
+        let backward = index.cumulative_factor(2027, 2025);
+        assert_eq!((forward * backward).round_dp(8), Decimal::ONE);
+    }
+
+    #[test]
+    fn test_indexed_rules_projects_bracket_minimums() {
+        let base: Arc<dyn TaxRules> = Arc::new(Rules2025::new());
+        let index = sample_index();
+        let rules = IndexedRules::new(Arc::clone(&base), 2027, 2025, &index);
+
+        assert_eq!(rules.year(), 2027);
+        // Bracket minimums should scale up and round to the nearest $50.
+        let base_bracket = &base.brackets(FilingStatus::Single)[1];
+        let projected_bracket = &rules.brackets(FilingStatus::Single)[1];
+        assert!(projected_bracket.min.as_decimal() > base_bracket.min.as_decimal());
+        assert_eq!(
+            (projected_bracket.min.as_decimal() / Decimal::new(50, 0)).fract(),
+            Decimal::ZERO
+        );
+    }
+
+    #[test]
+    fn test_indexed_rules_projects_standard_deduction_upward() {
+        let base: Arc<dyn TaxRules> = Arc::new(Rules2025::new());
+        let index = sample_index();
+        let rules = IndexedRules::new(Arc::clone(&base), 2027, 2025, &index);
+
+        assert!(
+            rules.standard_deduction_base(FilingStatus::Single).as_decimal()
+                > base.standard_deduction_base(FilingStatus::Single).as_decimal()
+        );
+    }
+
+    #[test]
+    fn test_flat_rate_parameters_pass_through_unchanged() {
+        let base: Arc<dyn TaxRules> = Arc::new(Rules2025::new());
+        let index = sample_index();
+        let rules = IndexedRules::new(Arc::clone(&base), 2027, 2025, &index);
+
+        assert_eq!(rules.qbi_deduction_rate(), base.qbi_deduction_rate());
+        assert_eq!(
+            rules.senior_bonus_deduction().unwrap().amount_per_person,
+            base.senior_bonus_deduction().unwrap().amount_per_person
+        );
+    }
+}
@@ -1,9 +1,10 @@
 //! Tax rules loader - loads rules from JSON data files.
 
 use crate::error::{TaxError, TaxResult};
-use crate::rules::Rules2025;
+use crate::rules::{IndexedRules, InflationIndex, ReformSpec, ReformedRules, Rules2025};
 use crate::traits::TaxRules;
 use crate::types::{TaxYear, MAX_SUPPORTED_YEAR, MIN_SUPPORTED_YEAR};
+use std::ops::RangeInclusive;
 use std::sync::Arc;
 
 /// Loader for tax rules by year.
@@ -33,15 +34,58 @@ impl RulesLoader {
         }
     }
 
+    /// Loads the baseline rules for `year` and applies a reform overlay.
+    ///
+    /// The reform's parameter values are validated (and clipped or rejected
+    /// per their `valid_values`) once here; the returned rules are a drop-in
+    /// `TaxRules` that consults the reform before falling back to the base
+    /// year's hard-coded values.
+    pub fn load_with_reform(
+        &self,
+        year: TaxYear,
+        reform: &ReformSpec,
+    ) -> TaxResult<Arc<dyn TaxRules>> {
+        let base = self.load(year)?;
+        let reformed = ReformedRules::new(base, reform)?;
+        Ok(Arc::new(reformed))
+    }
+
+    /// Synthesizes rules for `year` by projecting `base_year`'s dollar
+    /// parameters forward or backward using chained-CPI `index`.
+    ///
+    /// Unlike `load`, this does not require `year` to have a hard-coded
+    /// `TaxRules` implementation — only `base_year` does.
+    pub fn load_indexed(
+        &self,
+        year: TaxYear,
+        base_year: TaxYear,
+        index: &InflationIndex,
+    ) -> TaxResult<Arc<dyn TaxRules>> {
+        let base = self.load(base_year)?;
+        Ok(Arc::new(IndexedRules::new(base, year, base_year, index)))
+    }
+
     /// Returns true if the given year is supported.
     pub fn is_supported(&self, year: TaxYear) -> bool {
         (MIN_SUPPORTED_YEAR..=MAX_SUPPORTED_YEAR).contains(&year)
     }
 
-    /// Returns the range of supported years.
-    pub fn supported_years(&self) -> std::ops::RangeInclusive<TaxYear> {
+    /// Returns the range of years with a hard-coded `TaxRules` implementation.
+    pub fn supported_years(&self) -> RangeInclusive<TaxYear> {
         MIN_SUPPORTED_YEAR..=MAX_SUPPORTED_YEAR
     }
+
+    /// Returns the range of years that can be synthesized via `load_indexed`
+    /// with the given inflation index: from the earlier of the first
+    /// hard-coded year and the first year `index` has a growth rate for,
+    /// through the later of the last hard-coded year and the last year
+    /// `index` has a growth rate for.
+    pub fn indexed_supported_years(&self, index: &InflationIndex) -> RangeInclusive<TaxYear> {
+        match index.year_range() {
+            Some((min, max)) => MIN_SUPPORTED_YEAR.min(min)..=MAX_SUPPORTED_YEAR.max(max),
+            None => self.supported_years(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -67,4 +111,28 @@ mod tests {
         assert!(loader.is_supported(2025));
         assert!(!loader.is_supported(2020));
     }
+
+    #[test]
+    fn test_load_indexed_synthesizes_unsupported_year() {
+        use std::collections::HashMap;
+
+        let loader = RulesLoader::new();
+        let mut rates = HashMap::new();
+        rates.insert(2026, rust_decimal_macros::dec!(0.025));
+        let index = InflationIndex::new(rates);
+
+        let rules = loader.load_indexed(2026, 2025, &index).unwrap();
+        assert_eq!(rules.year(), 2026);
+        assert!(loader.indexed_supported_years(&index).contains(&2026));
+    }
+
+    #[test]
+    fn test_load_with_reform_empty_matches_base() {
+        let loader = RulesLoader::new();
+        let base = loader.load(2025).unwrap();
+        let reformed = loader
+            .load_with_reform(2025, &ReformSpec::default())
+            .unwrap();
+        assert_eq!(reformed.qbi_deduction_rate(), base.qbi_deduction_rate());
+    }
 }
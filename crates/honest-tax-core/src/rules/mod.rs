@@ -1,7 +1,11 @@
 //! Tax rules loading and management.
 
+mod indexed;
 mod loader;
+mod reform;
 mod y2025;
 
+pub use indexed::{IndexedRules, InflationIndex};
 pub use loader::RulesLoader;
+pub use reform::{OutOfRangeAction, ReformParameter, ReformSpec, ReformedRules, ValidValues};
 pub use y2025::Rules2025;
@@ -0,0 +1,337 @@
+//! JSON reform overlay for applying parameter overrides on top of a base
+//! year's `TaxRules`, without forking a year-specific implementation.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{TaxError, TaxResult};
+use crate::money::Money;
+use crate::traits::{
+    OvertimeDeduction, PhaseOut, SeniorBonusDeduction, StudentLoanInterestDeduction, TaxBracket,
+    TaxRules, TipIncomeDeduction,
+};
+use crate::types::{FilingStatus, TaxYear};
+
+/// What to do when a reform value falls outside its `valid_values` bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutOfRangeAction {
+    /// Clamp the value to the nearest bound.
+    Clip,
+    /// Reject the reform with a `TaxError`.
+    Stop,
+}
+
+/// Min/max bounds for a reform parameter, and what to do if violated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidValues {
+    /// Minimum permitted value (inclusive).
+    pub min: Decimal,
+    /// Maximum permitted value (inclusive).
+    pub max: Decimal,
+    /// Action to take when the reform value is out of bounds.
+    pub action: OutOfRangeAction,
+}
+
+impl ValidValues {
+    fn enforce(&self, name: &str, value: Decimal) -> TaxResult<Decimal> {
+        if value < self.min || value > self.max {
+            match self.action {
+                OutOfRangeAction::Clip => Ok(value.clamp(self.min, self.max)),
+                OutOfRangeAction::Stop => Err(TaxError::InvalidValue {
+                    field: name.to_string(),
+                    reason: format!(
+                        "reform value {} outside valid range [{}, {}]",
+                        value, self.min, self.max
+                    ),
+                }),
+            }
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+/// A single reform override: the new value plus the bounds that constrain it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReformParameter {
+    /// The overridden value.
+    pub value: Decimal,
+    /// Valid range and out-of-range handling for this parameter.
+    pub valid_values: ValidValues,
+}
+
+/// A user-supplied reform: a map of parameter name to overridden value.
+///
+/// Mirrors the JSON reform-file workflow used by policy-analysis tools like
+/// Tax-Calculator. Recognized parameter names:
+/// - `standard_deduction_<status>` (status codes: `s`, `mfj`, `mfs`, `hoh`, `qss`)
+/// - `child_tax_credit_max`
+/// - `additional_child_tax_credit_max`
+/// - `qbi_deduction_rate`
+/// - `ctc_phase_out_<status>_threshold` (status codes `s`, `mfj`, `mfs`
+///   only; HoH uses the `s` threshold and QSS the `mfj` one)
+/// - `ctc_phase_out_rate`
+/// - `bracket_rate_<status>_<index>` / `bracket_max_<status>_<index>`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReformSpec {
+    /// Parameter name -> overridden value.
+    #[serde(flatten)]
+    pub parameters: HashMap<String, ReformParameter>,
+}
+
+impl ReformSpec {
+    /// Parses a reform from a JSON string.
+    pub fn from_json(json: &str) -> TaxResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Resolves a named parameter, applying its valid-range enforcement.
+    ///
+    /// Returns `Ok(None)` if the reform does not touch this parameter.
+    fn resolve(&self, name: &str) -> TaxResult<Option<Decimal>> {
+        match self.parameters.get(name) {
+            Some(param) => Ok(Some(param.valid_values.enforce(name, param.value)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+fn status_key(status: FilingStatus) -> &'static str {
+    match status {
+        FilingStatus::Single => "s",
+        FilingStatus::MarriedFilingJointly => "mfj",
+        FilingStatus::MarriedFilingSeparately => "mfs",
+        FilingStatus::HeadOfHousehold => "hoh",
+        FilingStatus::QualifyingSurvivingSpouse => "qss",
+    }
+}
+
+/// Adapter that layers a `ReformSpec` over a baseline `TaxRules`.
+///
+/// All overridable values are resolved and validated once at construction
+/// time; trait methods are infallible lookups into the precomputed state,
+/// falling back to the base rules for anything the reform doesn't touch.
+#[derive(Debug)]
+pub struct ReformedRules {
+    base: Arc<dyn TaxRules>,
+    brackets: HashMap<FilingStatus, Vec<TaxBracket>>,
+    standard_deduction_overrides: HashMap<FilingStatus, Money>,
+    child_tax_credit_max: Option<Money>,
+    additional_child_tax_credit_max: Option<Money>,
+    qbi_deduction_rate: Option<Decimal>,
+    ctc_phase_out: PhaseOut,
+}
+
+impl ReformedRules {
+    /// Builds the reformed rules, validating every overridden value.
+    pub fn new(base: Arc<dyn TaxRules>, reform: &ReformSpec) -> TaxResult<Self> {
+        let mut brackets = HashMap::new();
+        for &status in FilingStatus::all() {
+            let mut owned: Vec<TaxBracket> = base.brackets(status).to_vec();
+            let key = status_key(status);
+            for (index, bracket) in owned.iter_mut().enumerate() {
+                if let Some(rate) = reform.resolve(&format!("bracket_rate_{key}_{index}"))? {
+                    bracket.rate = rate;
+                }
+                if let Some(max) = reform.resolve(&format!("bracket_max_{key}_{index}"))? {
+                    bracket.max = Some(Money::new(max));
+                }
+            }
+            brackets.insert(status, owned);
+        }
+
+        let mut standard_deduction_overrides = HashMap::new();
+        for &status in FilingStatus::all() {
+            let key = format!("standard_deduction_{}", status_key(status));
+            if let Some(value) = reform.resolve(&key)? {
+                standard_deduction_overrides.insert(status, Money::new(value));
+            }
+        }
+
+        let child_tax_credit_max = reform.resolve("child_tax_credit_max")?.map(Money::new);
+        let additional_child_tax_credit_max = reform
+            .resolve("additional_child_tax_credit_max")?
+            .map(Money::new);
+        let qbi_deduction_rate = reform.resolve("qbi_deduction_rate")?;
+
+        let mut ctc_phase_out = base.child_tax_credit_phase_out().clone();
+        if let Some(value) = reform.resolve("ctc_phase_out_s_threshold")? {
+            ctc_phase_out.single_threshold = Money::new(value);
+        }
+        if let Some(value) = reform.resolve("ctc_phase_out_mfj_threshold")? {
+            ctc_phase_out.joint_threshold = Money::new(value);
+        }
+        if let Some(value) = reform.resolve("ctc_phase_out_mfs_threshold")? {
+            ctc_phase_out.mfs_threshold = Money::new(value);
+        }
+        if let Some(value) = reform.resolve("ctc_phase_out_rate")? {
+            ctc_phase_out.rate = value;
+        }
+
+        Ok(Self {
+            base,
+            brackets,
+            standard_deduction_overrides,
+            child_tax_credit_max,
+            additional_child_tax_credit_max,
+            qbi_deduction_rate,
+            ctc_phase_out,
+        })
+    }
+}
+
+impl TaxRules for ReformedRules {
+    fn year(&self) -> TaxYear {
+        self.base.year()
+    }
+
+    fn brackets(&self, status: FilingStatus) -> &[TaxBracket] {
+        self.brackets
+            .get(&status)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    fn standard_deduction_base(&self, status: FilingStatus) -> Money {
+        self.standard_deduction_overrides
+            .get(&status)
+            .copied()
+            .unwrap_or_else(|| self.base.standard_deduction_base(status))
+    }
+
+    fn standard_deduction_age_65(&self, status: FilingStatus) -> Money {
+        self.base.standard_deduction_age_65(status)
+    }
+
+    fn standard_deduction_blind(&self, status: FilingStatus) -> Money {
+        self.base.standard_deduction_blind(status)
+    }
+
+    fn senior_bonus_deduction(&self) -> Option<SeniorBonusDeduction> {
+        self.base.senior_bonus_deduction()
+    }
+
+    fn tip_income_deduction(&self) -> Option<TipIncomeDeduction> {
+        self.base.tip_income_deduction()
+    }
+
+    fn overtime_deduction(&self) -> Option<OvertimeDeduction> {
+        self.base.overtime_deduction()
+    }
+
+    fn student_loan_interest_deduction(&self) -> Option<StudentLoanInterestDeduction> {
+        self.base.student_loan_interest_deduction()
+    }
+
+    fn child_tax_credit_max(&self) -> Money {
+        self.child_tax_credit_max
+            .unwrap_or_else(|| self.base.child_tax_credit_max())
+    }
+
+    fn additional_child_tax_credit_max(&self) -> Money {
+        self.additional_child_tax_credit_max
+            .unwrap_or_else(|| self.base.additional_child_tax_credit_max())
+    }
+
+    fn actc_earned_income_threshold(&self) -> Money {
+        self.base.actc_earned_income_threshold()
+    }
+
+    fn child_tax_credit_phase_out(&self) -> &PhaseOut {
+        &self.ctc_phase_out
+    }
+
+    fn credit_for_other_dependents(&self) -> Money {
+        self.base.credit_for_other_dependents()
+    }
+
+    fn personal_exemption(&self) -> Money {
+        self.base.personal_exemption()
+    }
+
+    fn qbi_deduction_rate(&self) -> Decimal {
+        self.qbi_deduction_rate
+            .unwrap_or_else(|| self.base.qbi_deduction_rate())
+    }
+
+    fn social_security_wage_base(&self) -> Money {
+        self.base.social_security_wage_base()
+    }
+
+    fn social_security_rate(&self) -> Decimal {
+        self.base.social_security_rate()
+    }
+
+    fn medicare_rate(&self) -> Decimal {
+        self.base.medicare_rate()
+    }
+
+    fn elective_deferral_limit(&self) -> Money {
+        self.base.elective_deferral_limit()
+    }
+
+    fn elective_deferral_catch_up_limit(&self) -> Money {
+        self.base.elective_deferral_catch_up_limit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Rules2025;
+    use rust_decimal_macros::dec;
+
+    fn reform_with(name: &str, value: Decimal, action: OutOfRangeAction) -> ReformSpec {
+        let mut parameters = HashMap::new();
+        parameters.insert(
+            name.to_string(),
+            ReformParameter {
+                value,
+                valid_values: ValidValues {
+                    min: dec!(0),
+                    max: dec!(1),
+                    action,
+                },
+            },
+        );
+        ReformSpec { parameters }
+    }
+
+    #[test]
+    fn test_qbi_rate_override() {
+        let base: Arc<dyn TaxRules> = Arc::new(Rules2025::new());
+        let reform = reform_with("qbi_deduction_rate", dec!(0.25), OutOfRangeAction::Stop);
+        let reformed = ReformedRules::new(base, &reform).unwrap();
+        assert_eq!(reformed.qbi_deduction_rate(), dec!(0.25));
+    }
+
+    #[test]
+    fn test_out_of_range_stop_errors() {
+        let base: Arc<dyn TaxRules> = Arc::new(Rules2025::new());
+        let reform = reform_with("qbi_deduction_rate", dec!(-0.10), OutOfRangeAction::Stop);
+        assert!(ReformedRules::new(base, &reform).is_err());
+    }
+
+    #[test]
+    fn test_out_of_range_clip_clamps() {
+        let base: Arc<dyn TaxRules> = Arc::new(Rules2025::new());
+        let reform = reform_with("qbi_deduction_rate", dec!(-0.10), OutOfRangeAction::Clip);
+        let reformed = ReformedRules::new(base, &reform).unwrap();
+        assert_eq!(reformed.qbi_deduction_rate(), dec!(0));
+    }
+
+    #[test]
+    fn test_unreformed_parameters_fall_through_to_base() {
+        let base: Arc<dyn TaxRules> = Arc::new(Rules2025::new());
+        let reform = reform_with("qbi_deduction_rate", dec!(0.25), OutOfRangeAction::Stop);
+        let reformed = ReformedRules::new(Arc::clone(&base), &reform).unwrap();
+        assert_eq!(
+            reformed.child_tax_credit_max(),
+            base.child_tax_credit_max()
+        );
+    }
+}
@@ -1,7 +1,10 @@
 //! Tax rules for 2025.
 
 use crate::money::Money;
-use crate::traits::{PhaseOut, SeniorBonusDeduction, TaxBracket, TaxRules};
+use crate::traits::{
+    OvertimeDeduction, PhaseOut, RatablePhaseOut, SeniorBonusDeduction,
+    StudentLoanInterestDeduction, TaxBracket, TaxRules, TipIncomeDeduction,
+};
 use crate::types::FilingStatus;
 use rust_decimal_macros::dec;
 
@@ -260,6 +263,18 @@ impl TaxRules for Rules2025 {
         })
     }
 
+    fn student_loan_interest_deduction(&self) -> Option<StudentLoanInterestDeduction> {
+        Some(StudentLoanInterestDeduction {
+            cap: Money::from_dollars(2_500),
+            phase_out: RatablePhaseOut {
+                single_threshold: Money::from_dollars(80_000),
+                joint_threshold: Money::from_dollars(165_000),
+                mfs_threshold: Money::ZERO,
+                range: Money::from_dollars(15_000),
+            },
+        })
+    }
+
     fn child_tax_credit_max(&self) -> Money {
         // Increased by One Big Beautiful Bill Act
         Money::from_dollars(2_200)
@@ -284,6 +299,53 @@ impl TaxRules for Rules2025 {
     fn qbi_deduction_rate(&self) -> rust_decimal::Decimal {
         dec!(0.20) // 20% deduction
     }
+
+    fn tip_income_deduction(&self) -> Option<TipIncomeDeduction> {
+        // One Big Beautiful Bill Act (2025-2028)
+        Some(TipIncomeDeduction {
+            cap: Money::from_dollars(25_000),
+            phase_out: PhaseOut {
+                single_threshold: Money::from_dollars(150_000),
+                joint_threshold: Money::from_dollars(300_000),
+                mfs_threshold: Money::from_dollars(150_000),
+                rate: dec!(0.10), // 10% reduction per dollar of MAGI over threshold
+            },
+            occupation_eligibility_required: true,
+        })
+    }
+
+    fn overtime_deduction(&self) -> Option<OvertimeDeduction> {
+        // One Big Beautiful Bill Act (2025-2028)
+        Some(OvertimeDeduction {
+            cap: Money::from_dollars(12_500),
+            phase_out: PhaseOut {
+                single_threshold: Money::from_dollars(150_000),
+                joint_threshold: Money::from_dollars(300_000),
+                mfs_threshold: Money::from_dollars(150_000),
+                rate: dec!(0.10),
+            },
+        })
+    }
+
+    fn social_security_wage_base(&self) -> Money {
+        Money::from_dollars(176_100)
+    }
+
+    fn social_security_rate(&self) -> rust_decimal::Decimal {
+        dec!(0.062)
+    }
+
+    fn medicare_rate(&self) -> rust_decimal::Decimal {
+        dec!(0.0145)
+    }
+
+    fn elective_deferral_limit(&self) -> Money {
+        Money::from_dollars(23_500)
+    }
+
+    fn elective_deferral_catch_up_limit(&self) -> Money {
+        Money::from_dollars(7_500)
+    }
 }
 
 #[cfg(test)]
@@ -341,4 +403,127 @@ mod tests {
         assert_eq!(bonus.amount_per_person, 6_000);
         assert_eq!(bonus.phase_out.single_threshold, Money::from_dollars(75_000));
     }
+
+    #[test]
+    fn test_calculate_tax_with_behavior_zero_elasticity_matches_baseline() {
+        let rules = Rules2025::new();
+        let income = Money::from_dollars(80_000);
+        let baseline = rules.calculate_tax(FilingStatus::Single, income);
+        let behavioral = rules.calculate_tax_with_behavior(
+            FilingStatus::Single,
+            income,
+            dec!(0),
+            dec!(0.22),
+            dec!(0.10),
+        );
+        assert_eq!(baseline, behavioral);
+    }
+
+    #[test]
+    fn test_calculate_tax_with_behavior_rate_cut_increases_reported_income() {
+        let rules = Rules2025::new();
+        let income = Money::from_dollars(80_000);
+        let unchanged = rules.calculate_tax_with_behavior(
+            FilingStatus::Single,
+            income,
+            dec!(0.25),
+            dec!(0.22),
+            dec!(0.22),
+        );
+        let after_cut = rules.calculate_tax_with_behavior(
+            FilingStatus::Single,
+            income,
+            dec!(0.25),
+            dec!(0.22),
+            dec!(0.12),
+        );
+        assert!(after_cut > unchanged);
+    }
+
+    #[test]
+    fn test_tip_income_deduction_capped_and_requires_eligible_occupation() {
+        let rules = Rules2025::new();
+        let deduction = rules.calculate_tip_income_deduction(
+            FilingStatus::Single,
+            Money::from_dollars(30_000),
+            Money::from_dollars(100_000),
+            true,
+        );
+        assert_eq!(deduction, Money::from_dollars(25_000));
+
+        let ineligible = rules.calculate_tip_income_deduction(
+            FilingStatus::Single,
+            Money::from_dollars(30_000),
+            Money::from_dollars(100_000),
+            false,
+        );
+        assert_eq!(ineligible, Money::ZERO);
+    }
+
+    #[test]
+    fn test_overtime_deduction_phases_out_with_agi() {
+        let rules = Rules2025::new();
+        let below_threshold = rules.calculate_overtime_deduction(
+            FilingStatus::Single,
+            Money::from_dollars(10_000),
+            Money::from_dollars(100_000),
+        );
+        assert_eq!(below_threshold, Money::from_dollars(10_000));
+
+        let above_threshold = rules.calculate_overtime_deduction(
+            FilingStatus::Single,
+            Money::from_dollars(10_000),
+            Money::from_dollars(151_000),
+        );
+        assert_eq!(above_threshold, Money::from_dollars(9_900));
+    }
+
+    #[test]
+    fn test_payroll_tax_under_wage_base() {
+        let rules = Rules2025::new();
+        let payroll =
+            rules.calculate_payroll_tax(FilingStatus::Single, Money::from_dollars(80_000), Money::ZERO);
+        assert_eq!(
+            payroll.social_security,
+            Money::from_dollars(80_000).multiply_rate(dec!(0.062))
+        );
+        assert_eq!(
+            payroll.medicare,
+            Money::from_dollars(80_000).multiply_rate(dec!(0.0145))
+        );
+        assert_eq!(payroll.additional_medicare, Money::ZERO);
+    }
+
+    #[test]
+    fn test_payroll_tax_over_wage_base_and_additional_medicare() {
+        let rules = Rules2025::new();
+        let wages = Money::from_dollars(250_000);
+        let payroll = rules.calculate_payroll_tax(FilingStatus::Single, wages, Money::ZERO);
+
+        let wage_base = rules.social_security_wage_base();
+        assert_eq!(
+            payroll.social_security,
+            wage_base.multiply_rate(dec!(0.062))
+        );
+        assert_eq!(payroll.medicare, wages.multiply_rate(dec!(0.0145)));
+
+        let threshold = rules.additional_medicare_threshold(FilingStatus::Single);
+        assert_eq!(
+            payroll.additional_medicare,
+            wages.saturating_sub(threshold).multiply_rate(dec!(0.009))
+        );
+    }
+
+    #[test]
+    fn test_payroll_tax_self_employment_doubles_rates() {
+        let rules = Rules2025::new();
+        let se_income = Money::from_dollars(40_000);
+        let payroll = rules.calculate_payroll_tax(FilingStatus::Single, Money::ZERO, se_income);
+
+        assert_eq!(
+            payroll.social_security,
+            se_income.multiply_rate(dec!(0.124))
+        );
+        assert_eq!(payroll.medicare, se_income.multiply_rate(dec!(0.029)));
+    }
 }
@@ -0,0 +1,113 @@
+//! Arizona state tax rules for 2025 (Form 140).
+
+use crate::money::Money;
+use crate::states::{ChildDeductionBand, StateCode, StateTaxRules};
+use crate::traits::TaxBracket;
+use crate::types::FilingStatus;
+use rust_decimal_macros::dec;
+
+/// Arizona tax rules for tax year 2025.
+///
+/// Arizona has used a single flat 2.5% rate on taxable income since tax
+/// year 2023; there is no bracket structure to speak of, but the trait
+/// still expresses it as a one-bracket table for consistency with states
+/// that do have graduated rates.
+///
+/// Sources:
+/// - Arizona Form 140 instructions (2024)
+/// - Ariz. Rev. Stat. § 43-1011
+#[derive(Debug, Clone)]
+pub struct StateRulesAz2025 {
+    brackets: Vec<TaxBracket>,
+}
+
+impl Default for StateRulesAz2025 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StateRulesAz2025 {
+    /// Creates a new `StateRulesAz2025` instance.
+    pub fn new() -> Self {
+        Self {
+            brackets: vec![TaxBracket {
+                rate: dec!(0.025),
+                min: Money::ZERO,
+                max: None,
+            }],
+        }
+    }
+}
+
+impl StateTaxRules for StateRulesAz2025 {
+    fn state(&self) -> StateCode {
+        StateCode::Az
+    }
+
+    fn year(&self) -> crate::types::TaxYear {
+        2025
+    }
+
+    fn brackets(&self, _status: FilingStatus) -> &[TaxBracket] {
+        &self.brackets
+    }
+
+    fn standard_deduction(&self, status: FilingStatus) -> Money {
+        match status {
+            FilingStatus::Single | FilingStatus::MarriedFilingSeparately => {
+                Money::from_dollars(14_600)
+            }
+            FilingStatus::MarriedFilingJointly | FilingStatus::QualifyingSurvivingSpouse => {
+                Money::from_dollars(29_200)
+            }
+            FilingStatus::HeadOfHousehold => Money::from_dollars(21_900),
+        }
+    }
+
+    fn child_deduction_bands(&self, _status: FilingStatus) -> &[ChildDeductionBand] {
+        // Arizona does not have an income-banded per-child deduction; its
+        // dependent benefit is a flat state credit, which these rules do not
+        // compute (`credits` returns zero).
+        &[]
+    }
+
+    fn subtractions(&self, _status: FilingStatus, _federal_agi: Money) -> Money {
+        // Arizona exempts U.S. government obligation interest, but that
+        // subtraction is not computed here; this always returns zero.
+        Money::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flat_rate_applies_to_all_statuses() {
+        let rules = StateRulesAz2025::new();
+        for &status in FilingStatus::all() {
+            let tax = rules.calculate_tax(status, Money::from_dollars(100_000));
+            assert_eq!(tax, Money::from_dollars(100_000).multiply_rate(dec!(0.025)));
+        }
+    }
+
+    #[test]
+    fn test_standard_deduction_varies_by_status() {
+        let rules = StateRulesAz2025::new();
+        assert_eq!(
+            rules.standard_deduction(FilingStatus::Single),
+            Money::from_dollars(14_600)
+        );
+        assert_eq!(
+            rules.standard_deduction(FilingStatus::MarriedFilingJointly),
+            Money::from_dollars(29_200)
+        );
+    }
+
+    #[test]
+    fn test_no_child_deduction_bands() {
+        let rules = StateRulesAz2025::new();
+        assert!(rules.child_deduction_bands(FilingStatus::Single).is_empty());
+    }
+}
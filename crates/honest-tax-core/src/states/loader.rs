@@ -0,0 +1,57 @@
+//! State tax rules loader - loads rules by state and year.
+
+use crate::error::{TaxError, TaxResult};
+use crate::states::{StateCode, StateRulesAz2025, StateTaxRules};
+use crate::types::TaxYear;
+use std::sync::Arc;
+
+/// Loader for state tax rules by state and year.
+#[derive(Debug, Default)]
+pub struct StateRulesLoader {
+    // Concrete implementations are registered in `load` as they're added.
+}
+
+impl StateRulesLoader {
+    /// Creates a new state rules loader.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the state tax rules for the given state and year.
+    pub fn load(&self, state: StateCode, year: TaxYear) -> TaxResult<Arc<dyn StateTaxRules>> {
+        match (state, year) {
+            (StateCode::Az, 2025) => Ok(Arc::new(StateRulesAz2025::new())),
+            // Concrete per-state rules are registered here as they're implemented.
+            _ => Err(TaxError::StateRulesNotFound {
+                state: state.code().to_string(),
+                year,
+            }),
+        }
+    }
+
+    /// Returns true if the given state and year are supported.
+    pub fn is_supported(&self, state: StateCode, year: TaxYear) -> bool {
+        self.load(state, year).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_unsupported_state_errors() {
+        let loader = StateRulesLoader::new();
+        assert!(loader.load(StateCode::Nc, 2025).is_err());
+        assert!(!loader.is_supported(StateCode::Nc, 2025));
+    }
+
+    #[test]
+    fn test_load_az_2025() {
+        let loader = StateRulesLoader::new();
+        let rules = loader.load(StateCode::Az, 2025).unwrap();
+        assert_eq!(rules.state(), StateCode::Az);
+        assert_eq!(rules.year(), 2025);
+        assert!(loader.is_supported(StateCode::Az, 2025));
+    }
+}
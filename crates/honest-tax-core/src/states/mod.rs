@@ -0,0 +1,13 @@
+//! State income tax rules, layered on top of the federal calculation.
+//!
+//! Mirrors the `rules`/`traits` split used for federal tax: `StateTaxRules`
+//! is the per-state, per-year parameter trait, and `StateRulesLoader` is the
+//! lookup entry point.
+
+mod az2025;
+mod loader;
+mod rules;
+
+pub use az2025::StateRulesAz2025;
+pub use loader::StateRulesLoader;
+pub use rules::{ChildDeductionBand, StateCode, StateTaxRules};
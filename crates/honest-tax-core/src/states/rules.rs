@@ -0,0 +1,298 @@
+//! Trait for year-specific, per-state tax rules.
+
+use crate::money::Money;
+use crate::traits::TaxBracket;
+use crate::types::{FilingStatus, TaxYear};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// USPS state/territory abbreviation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+#[allow(missing_docs)]
+pub enum StateCode {
+    Al,
+    Ak,
+    Az,
+    Ar,
+    Ca,
+    Co,
+    Ct,
+    De,
+    Dc,
+    Fl,
+    Ga,
+    Hi,
+    Id,
+    Il,
+    In,
+    Ia,
+    Ks,
+    Ky,
+    La,
+    Me,
+    Md,
+    Ma,
+    Mi,
+    Mn,
+    Ms,
+    Mo,
+    Mt,
+    Ne,
+    Nv,
+    Nh,
+    Nj,
+    Nm,
+    Ny,
+    Nc,
+    Nd,
+    Oh,
+    Ok,
+    Or,
+    Pa,
+    Ri,
+    Sc,
+    Sd,
+    Tn,
+    Tx,
+    Ut,
+    Vt,
+    Va,
+    Wa,
+    Wv,
+    Wi,
+    Wy,
+}
+
+impl StateCode {
+    /// Returns the two-letter USPS abbreviation for this state.
+    pub fn code(&self) -> &'static str {
+        match self {
+            StateCode::Al => "AL",
+            StateCode::Ak => "AK",
+            StateCode::Az => "AZ",
+            StateCode::Ar => "AR",
+            StateCode::Ca => "CA",
+            StateCode::Co => "CO",
+            StateCode::Ct => "CT",
+            StateCode::De => "DE",
+            StateCode::Dc => "DC",
+            StateCode::Fl => "FL",
+            StateCode::Ga => "GA",
+            StateCode::Hi => "HI",
+            StateCode::Id => "ID",
+            StateCode::Il => "IL",
+            StateCode::In => "IN",
+            StateCode::Ia => "IA",
+            StateCode::Ks => "KS",
+            StateCode::Ky => "KY",
+            StateCode::La => "LA",
+            StateCode::Me => "ME",
+            StateCode::Md => "MD",
+            StateCode::Ma => "MA",
+            StateCode::Mi => "MI",
+            StateCode::Mn => "MN",
+            StateCode::Ms => "MS",
+            StateCode::Mo => "MO",
+            StateCode::Mt => "MT",
+            StateCode::Ne => "NE",
+            StateCode::Nv => "NV",
+            StateCode::Nh => "NH",
+            StateCode::Nj => "NJ",
+            StateCode::Nm => "NM",
+            StateCode::Ny => "NY",
+            StateCode::Nc => "NC",
+            StateCode::Nd => "ND",
+            StateCode::Oh => "OH",
+            StateCode::Ok => "OK",
+            StateCode::Or => "OR",
+            StateCode::Pa => "PA",
+            StateCode::Ri => "RI",
+            StateCode::Sc => "SC",
+            StateCode::Sd => "SD",
+            StateCode::Tn => "TN",
+            StateCode::Tx => "TX",
+            StateCode::Ut => "UT",
+            StateCode::Vt => "VT",
+            StateCode::Va => "VA",
+            StateCode::Wa => "WA",
+            StateCode::Wv => "WV",
+            StateCode::Wi => "WI",
+            StateCode::Wy => "WY",
+        }
+    }
+}
+
+impl std::fmt::Display for StateCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// One band of an income-banded child deduction table.
+///
+/// `range` is `(lower_bound_inclusive, upper_bound_exclusive)`; `None` for
+/// the upper bound means "and above".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChildDeductionBand {
+    /// AGI range this band applies to.
+    pub range: (Money, Option<Money>),
+    /// Deduction amount per qualifying child in this band.
+    pub per_child_amount: Money,
+}
+
+impl ChildDeductionBand {
+    fn contains(&self, agi: Money) -> bool {
+        let (lower, upper) = self.range;
+        match upper {
+            Some(upper) => agi >= lower && agi < upper,
+            None => agi >= lower,
+        }
+    }
+}
+
+/// Trait for year-specific, per-state tax rules.
+///
+/// Implementations provide the numeric constants needed to layer a state
+/// income tax calculation on top of the federal one.
+pub trait StateTaxRules: Send + Sync {
+    /// Returns the state these rules apply to.
+    fn state(&self) -> StateCode;
+
+    /// Returns the tax year these rules apply to.
+    fn year(&self) -> TaxYear;
+
+    /// Returns the state's tax brackets for the given filing status.
+    fn brackets(&self, status: FilingStatus) -> &[TaxBracket];
+
+    /// Calculates state tax using the state's tax brackets.
+    fn calculate_tax(&self, status: FilingStatus, taxable_income: Money) -> Money {
+        self.brackets(status)
+            .iter()
+            .map(|bracket| bracket.tax_for_income(taxable_income))
+            .sum()
+    }
+
+    /// Returns the state standard deduction for the given filing status.
+    fn standard_deduction(&self, status: FilingStatus) -> Money;
+
+    /// Returns the ordered, non-overlapping income bands for the
+    /// income-banded child deduction, for the given filing status.
+    fn child_deduction_bands(&self, status: FilingStatus) -> &[ChildDeductionBand];
+
+    /// Calculates the child deduction: the per-child amount for the band
+    /// containing `agi`, multiplied by `num_children`.
+    fn child_deduction(&self, status: FilingStatus, agi: Money, num_children: u32) -> Money {
+        let per_child = self
+            .child_deduction_bands(status)
+            .iter()
+            .find(|band| band.contains(agi))
+            .map(|band| band.per_child_amount)
+            .unwrap_or(Money::ZERO);
+
+        per_child.multiply_rate(Decimal::from(num_children))
+    }
+
+    /// Returns state-specific additions to federal AGI: income taxable by
+    /// the state but excluded from federal AGI (e.g. interest from other
+    /// states' municipal bonds).
+    fn additions(&self, _status: FilingStatus, _federal_agi: Money) -> Money {
+        Money::ZERO
+    }
+
+    /// Returns state-specific subtractions from federal AGI: income taxed
+    /// federally but exempt at the state level (e.g. U.S. government bond
+    /// interest).
+    fn subtractions(&self, _status: FilingStatus, _federal_agi: Money) -> Money {
+        Money::ZERO
+    }
+
+    /// Returns nonrefundable state credits (e.g. a property tax credit)
+    /// available against the calculated state tax.
+    fn credits(&self, _status: FilingStatus, _federal_agi: Money) -> Money {
+        Money::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    struct NcStyleRules {
+        brackets: Vec<TaxBracket>,
+        bands: Vec<ChildDeductionBand>,
+    }
+
+    impl NcStyleRules {
+        fn new() -> Self {
+            Self {
+                brackets: vec![TaxBracket {
+                    rate: dec!(0.045),
+                    min: Money::ZERO,
+                    max: None,
+                }],
+                bands: vec![
+                    ChildDeductionBand {
+                        range: (Money::ZERO, Some(Money::from_dollars(40_000))),
+                        per_child_amount: Money::from_dollars(3_000),
+                    },
+                    ChildDeductionBand {
+                        range: (Money::from_dollars(40_000), Some(Money::from_dollars(80_000))),
+                        per_child_amount: Money::from_dollars(1_500),
+                    },
+                    ChildDeductionBand {
+                        range: (Money::from_dollars(80_000), None),
+                        per_child_amount: Money::ZERO,
+                    },
+                ],
+            }
+        }
+    }
+
+    impl StateTaxRules for NcStyleRules {
+        fn state(&self) -> StateCode {
+            StateCode::Nc
+        }
+
+        fn year(&self) -> TaxYear {
+            2025
+        }
+
+        fn brackets(&self, _status: FilingStatus) -> &[TaxBracket] {
+            &self.brackets
+        }
+
+        fn standard_deduction(&self, _status: FilingStatus) -> Money {
+            Money::from_dollars(12_750)
+        }
+
+        fn child_deduction_bands(&self, _status: FilingStatus) -> &[ChildDeductionBand] {
+            &self.bands
+        }
+    }
+
+    #[test]
+    fn test_calculate_tax_flat_rate() {
+        let rules = NcStyleRules::new();
+        let tax = rules.calculate_tax(FilingStatus::Single, Money::from_dollars(50_000));
+        assert_eq!(tax, Money::from_dollars(50_000).multiply_rate(dec!(0.045)));
+    }
+
+    #[test]
+    fn test_child_deduction_selects_matching_band() {
+        let rules = NcStyleRules::new();
+        assert_eq!(
+            rules.child_deduction(FilingStatus::Single, Money::from_dollars(30_000), 2),
+            Money::from_dollars(6_000)
+        );
+        assert_eq!(
+            rules.child_deduction(FilingStatus::Single, Money::from_dollars(50_000), 2),
+            Money::from_dollars(3_000)
+        );
+        assert_eq!(
+            rules.child_deduction(FilingStatus::Single, Money::from_dollars(90_000), 2),
+            Money::ZERO
+        );
+    }
+}
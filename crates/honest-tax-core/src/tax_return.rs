@@ -0,0 +1,187 @@
+//! Builder for assembling a `Form1040` from source documents.
+
+use crate::inputs::{Form1099Div, Form1099Int, Form1099R, W2};
+use crate::money::Money;
+use crate::outputs::{Form1040, TaxBrackets};
+use crate::traits::{InputForm, TaxRules};
+use crate::types::{FilingStatus, TaxYear};
+use std::collections::HashMap;
+
+/// Builds a `Form1040` by aggregating source documents — W-2s, 1099-INTs,
+/// 1099-DIVs, and 1099-Rs — mirroring the `addForm`-style assembly used by
+/// other tax libraries.
+#[derive(Debug, Clone)]
+pub struct TaxReturn {
+    tax_year: TaxYear,
+    filing_status: FilingStatus,
+    w2s: Vec<W2>,
+    f1099_ints: Vec<Form1099Int>,
+    f1099_divs: Vec<Form1099Div>,
+    f1099_rs: Vec<Form1099R>,
+}
+
+impl TaxReturn {
+    /// Creates a new, empty tax return for the given year and filing status.
+    pub fn new(tax_year: TaxYear, filing_status: FilingStatus) -> Self {
+        Self {
+            tax_year,
+            filing_status,
+            w2s: Vec::new(),
+            f1099_ints: Vec::new(),
+            f1099_divs: Vec::new(),
+            f1099_rs: Vec::new(),
+        }
+    }
+
+    /// Adds a W-2.
+    pub fn add_w2(&mut self, w2: W2) -> &mut Self {
+        self.w2s.push(w2);
+        self
+    }
+
+    /// Adds a 1099-INT.
+    pub fn add_1099_int(&mut self, form: Form1099Int) -> &mut Self {
+        self.f1099_ints.push(form);
+        self
+    }
+
+    /// Adds a 1099-DIV.
+    pub fn add_1099_div(&mut self, form: Form1099Div) -> &mut Self {
+        self.f1099_divs.push(form);
+        self
+    }
+
+    /// Adds a 1099-R.
+    pub fn add_1099_r(&mut self, form: Form1099R) -> &mut Self {
+        self.f1099_rs.push(form);
+        self
+    }
+
+    /// Builds a `Form1040`, summing every source document into its
+    /// corresponding line, then running the full recalculation so every
+    /// downstream total reflects the aggregated inputs.
+    pub fn build(
+        &self,
+        rules: &dyn TaxRules,
+        brackets_by_status: &HashMap<FilingStatus, TaxBrackets>,
+    ) -> Form1040 {
+        let mut form = Form1040::new(self.tax_year);
+        form.filing_status = self.filing_status;
+
+        form.line_1a = self.w2s.iter().filter_map(|w2| w2.wages()).sum();
+        form.line_25a = self.w2s.iter().filter_map(|w2| w2.federal_withholding()).sum();
+
+        form.line_2b = self
+            .f1099_ints
+            .iter()
+            .filter_map(|f| f.taxable_interest())
+            .sum();
+        form.line_2a = self
+            .f1099_ints
+            .iter()
+            .filter_map(|f| f.tax_exempt_interest())
+            .sum();
+
+        form.line_3a = self
+            .f1099_divs
+            .iter()
+            .filter_map(|f| f.qualified_dividends())
+            .sum();
+        form.line_3b = self
+            .f1099_divs
+            .iter()
+            .filter_map(|f| f.ordinary_dividends())
+            .sum();
+
+        form.line_5a = self.f1099_rs.iter().filter_map(|f| f.pension_gross()).sum();
+        form.line_5b = self
+            .f1099_rs
+            .iter()
+            .filter_map(|f| f.pension_taxable())
+            .sum();
+
+        let f1099_withholding: Money = self
+            .f1099_ints
+            .iter()
+            .filter_map(|f| f.federal_withholding())
+            .chain(self.f1099_divs.iter().filter_map(|f| f.federal_withholding()))
+            .chain(self.f1099_rs.iter().filter_map(|f| f.federal_withholding()))
+            .sum();
+        form.line_25b = f1099_withholding;
+
+        form.recalculate(rules, brackets_by_status);
+        form
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Rules2025;
+    use rust_decimal_macros::dec;
+
+    fn single_2025_brackets() -> HashMap<FilingStatus, TaxBrackets> {
+        let mut map = HashMap::new();
+        map.insert(
+            FilingStatus::Single,
+            TaxBrackets::new(vec![crate::outputs::TaxBracketStep {
+                upper_limit: None,
+                flat_amount: Money::ZERO,
+                marginal_rate: dec!(0.10),
+            }]),
+        );
+        map
+    }
+
+    #[test]
+    fn test_build_aggregates_source_documents() {
+        let mut tax_return = TaxReturn::new(2025, FilingStatus::Single);
+        tax_return
+            .add_w2(W2 {
+                box_1_wages: Money::from_dollars(60_000),
+                box_2_federal_tax_withheld: Money::from_dollars(8_000),
+                ..W2::default()
+            })
+            .add_1099_int(Form1099Int {
+                id: "int-1".to_string(),
+                tax_year: 2025,
+                payer_name: "Bank".to_string(),
+                box_1_interest_income: Money::from_dollars(500),
+                box_4_federal_tax_withheld: Money::ZERO,
+                box_8_tax_exempt_interest: Money::ZERO,
+            })
+            .add_1099_div(Form1099Div {
+                id: "div-1".to_string(),
+                tax_year: 2025,
+                payer_name: "Brokerage".to_string(),
+                box_1a_ordinary_dividends: Money::from_dollars(300),
+                box_1b_qualified_dividends: Money::from_dollars(300),
+                box_2a_capital_gain_distributions: Money::ZERO,
+                box_4_federal_tax_withheld: Money::from_dollars(30),
+                box_7_foreign_tax_paid: Money::ZERO,
+                foreign_country: String::new(),
+                box_1a_foreign_source_dividends: Money::ZERO,
+            })
+            .add_1099_r(Form1099R {
+                id: "r-1".to_string(),
+                tax_year: 2025,
+                payer_name: "Pension Fund".to_string(),
+                box_1_gross_distribution: Money::from_dollars(10_000),
+                box_2a_taxable_amount: Money::from_dollars(9_000),
+                box_4_federal_tax_withheld: Money::from_dollars(1_000),
+            });
+
+        let rules = Rules2025::new();
+        let form = tax_return.build(&rules, &single_2025_brackets());
+
+        assert_eq!(form.line_1a, Money::from_dollars(60_000));
+        assert_eq!(form.line_25a, Money::from_dollars(8_000));
+        assert_eq!(form.line_2b, Money::from_dollars(500));
+        assert_eq!(form.line_3a, Money::from_dollars(300));
+        assert_eq!(form.line_3b, Money::from_dollars(300));
+        assert_eq!(form.line_5a, Money::from_dollars(10_000));
+        assert_eq!(form.line_5b, Money::from_dollars(9_000));
+        assert_eq!(form.line_25b, Money::from_dollars(1_030));
+        assert_eq!(form.line_1z, Money::from_dollars(60_000));
+    }
+}
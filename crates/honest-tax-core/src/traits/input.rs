@@ -2,6 +2,37 @@
 
 use crate::money::Money;
 use crate::types::{InputFormType, TaxYear};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A single item of foreign-source income, carrying both the source-currency
+/// amount and its USD conversion, for Form 1116 foreign tax credit purposes.
+///
+/// Modeled after the `investments` crate's `ForeignIncome`/`CurrencyIncome`
+/// records: a source-currency amount is converted to USD at a dated
+/// exchange rate, and any foreign tax withheld or paid on that income is
+/// tracked alongside it so it can feed the credit computation without
+/// contaminating the core `Money` arithmetic with non-USD amounts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForeignIncomeItem {
+    /// Category of income (e.g. "dividend", "interest", "passive").
+    pub income_type: String,
+    /// ISO 4217 currency code the income was originally denominated in.
+    pub source_currency: String,
+    /// Amount in the source currency, before conversion.
+    pub source_amount: Decimal,
+    /// Exchange rate applied to convert `source_amount` to USD.
+    pub exchange_rate: Decimal,
+    /// `source_amount` converted to USD at `exchange_rate`.
+    pub converted: Money,
+    /// Foreign tax paid or withheld on this income, converted to USD.
+    pub foreign_tax_paid: Money,
+    /// Country of the income source: an ISO 3166-1 alpha-2 code, or "RIC"
+    /// when a regulated investment company reports tax pooled across
+    /// several source countries (as 1099-DIV allows).
+    pub country_code: String,
+}
 
 /// Trait implemented by all input forms (W-2, 1099s, etc.).
 ///
@@ -114,6 +145,19 @@ pub trait InputForm: Send + Sync + std::fmt::Debug {
         None
     }
 
+    /// Foreign-source income items reported on this form (1099-DIV Box 7
+    /// and similar), for Form 1116 foreign tax credit purposes.
+    fn foreign_income(&self) -> Vec<ForeignIncomeItem> {
+        Vec::new()
+    }
+
+    /// Foreign-source income items on this form for which foreign tax was
+    /// actually paid or withheld (the subset of `foreign_income()` that
+    /// forms the Form 1116 credit basis).
+    fn foreign_tax_paid(&self) -> Vec<ForeignIncomeItem> {
+        Vec::new()
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Withholding extraction methods
     // ─────────────────────────────────────────────────────────────────────────
@@ -128,6 +172,16 @@ pub trait InputForm: Send + Sync + std::fmt::Debug {
         None
     }
 
+    /// State income tax withheld, partitioned by state abbreviation (W-2
+    /// Box 15/17 and similar multi-state breakdowns).
+    ///
+    /// Forms that only ever carry a single state's withholding can rely on
+    /// [`InputForm::state_withholding`] instead; this is for multi-state
+    /// taxpayers where `total_state_withholding()` would conflate states.
+    fn state_withholding_by_state(&self) -> Vec<(String, Money)> {
+        Vec::new()
+    }
+
     /// Local income tax withheld.
     fn local_withholding(&self) -> Option<Money> {
         None
@@ -225,6 +279,16 @@ pub trait InputFormCollection {
 
     /// Sum all state withholding across all input forms.
     fn total_state_withholding(&self) -> Money;
+
+    /// Sum all foreign tax paid across all input forms' foreign income items.
+    fn total_foreign_tax_paid(&self) -> Money;
+
+    /// Sum state withholding across all input forms, partitioned by state
+    /// abbreviation, for multi-state taxpayers.
+    fn total_state_withholding_by_state(&self) -> HashMap<String, Money>;
+
+    /// Sum all student loan interest paid across all input forms.
+    fn total_student_loan_interest(&self) -> Money;
 }
 
 impl<T: AsRef<[Box<dyn InputForm>]>> InputFormCollection for T {
@@ -269,4 +333,29 @@ impl<T: AsRef<[Box<dyn InputForm>]>> InputFormCollection for T {
             .filter_map(|f| f.state_withholding())
             .sum()
     }
+
+    fn total_foreign_tax_paid(&self) -> Money {
+        self.as_ref()
+            .iter()
+            .flat_map(|f| f.foreign_tax_paid())
+            .map(|item| item.foreign_tax_paid)
+            .sum()
+    }
+
+    fn total_state_withholding_by_state(&self) -> HashMap<String, Money> {
+        let mut totals: HashMap<String, Money> = HashMap::new();
+        for form in self.as_ref() {
+            for (state, amount) in form.state_withholding_by_state() {
+                *totals.entry(state).or_insert(Money::ZERO) += amount;
+            }
+        }
+        totals
+    }
+
+    fn total_student_loan_interest(&self) -> Money {
+        self.as_ref()
+            .iter()
+            .filter_map(|f| f.student_loan_interest())
+            .sum()
+    }
 }
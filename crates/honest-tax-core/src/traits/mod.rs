@@ -4,6 +4,9 @@ mod input;
 mod output;
 mod rules;
 
-pub use input::{InputForm, InputFormCollection};
+pub use input::{ForeignIncomeItem, InputForm, InputFormCollection};
 pub use output::{FormLine, FormSchema, FormValue, FormValueType, FormLineSpec, OutputForm};
-pub use rules::{PhaseOut, SeniorBonusDeduction, TaxBracket, TaxRules};
+pub use rules::{
+    OvertimeDeduction, PayrollTax, PhaseOut, RatablePhaseOut, SeniorBonusDeduction,
+    StudentLoanInterestDeduction, TaxBracket, TaxRules, TipIncomeDeduction,
+};
@@ -3,6 +3,7 @@
 use crate::money::Money;
 use crate::types::{FilingStatus, TaxYear};
 use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 
 /// A single tax bracket.
@@ -114,6 +115,41 @@ pub trait TaxRules: Send + Sync {
         total_tax
     }
 
+    /// Calculates tax with an elasticity-of-taxable-income behavioral response.
+    ///
+    /// Models taxpayers reporting more (or less) income as their marginal
+    /// net-of-tax rate (`1 - tau`) changes: `dz/z = elasticity * [(1 -
+    /// reform_mtr) - (1 - baseline_mtr)] / (1 - baseline_mtr)`. The adjusted
+    /// income `z' = baseline_taxable_income * (1 + dz/z)` is floored at zero
+    /// and then run through `calculate_tax`. An `elasticity` of `0`
+    /// reproduces `calculate_tax`'s behavior exactly, as does a
+    /// `baseline_mtr` within `EPSILON` of `1` (to avoid dividing by ~zero).
+    fn calculate_tax_with_behavior(
+        &self,
+        status: FilingStatus,
+        baseline_taxable_income: Money,
+        elasticity: Decimal,
+        baseline_mtr: Decimal,
+        reform_mtr: Decimal,
+    ) -> Money {
+        const EPSILON: Decimal = dec!(0.0001);
+
+        let net_of_tax_denominator = Decimal::ONE - baseline_mtr;
+        if net_of_tax_denominator.abs() < EPSILON {
+            return self.calculate_tax(status, baseline_taxable_income);
+        }
+
+        let keep_share_change =
+            ((Decimal::ONE - reform_mtr) - (Decimal::ONE - baseline_mtr)) / net_of_tax_denominator;
+        let proportional_response = elasticity * keep_share_change;
+
+        let adjusted_income =
+            baseline_taxable_income.multiply_rate(Decimal::ONE + proportional_response);
+        let floored_income = adjusted_income.max(Money::ZERO);
+
+        self.calculate_tax(status, floored_income)
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Standard deduction
     // ─────────────────────────────────────────────────────────────────────────
@@ -220,6 +256,222 @@ pub trait TaxRules: Send + Sync {
 
     /// Qualified Business Income deduction rate (Section 199A).
     fn qbi_deduction_rate(&self) -> Decimal;
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // OBBBA tip income / overtime deductions
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Above-the-line deduction for qualified tip income (OBBBA 2025-2028).
+    fn tip_income_deduction(&self) -> Option<TipIncomeDeduction> {
+        None
+    }
+
+    /// Above-the-line deduction for qualified overtime premium pay (OBBBA 2025-2028).
+    fn overtime_deduction(&self) -> Option<OvertimeDeduction> {
+        None
+    }
+
+    /// Calculates the allowed tip-income deduction for reported tip wages.
+    ///
+    /// Applies the per-return cap and the MAGI phase-out reduction; returns
+    /// zero if the taxpayer's occupation isn't eligible or the rules don't
+    /// define this deduction for the year.
+    fn calculate_tip_income_deduction(
+        &self,
+        status: FilingStatus,
+        reported_tip_wages: Money,
+        agi: Money,
+        occupation_eligible: bool,
+    ) -> Money {
+        let Some(config) = self.tip_income_deduction() else {
+            return Money::ZERO;
+        };
+        if config.occupation_eligibility_required && !occupation_eligible {
+            return Money::ZERO;
+        }
+
+        let capped = reported_tip_wages.min(config.cap);
+        let reduction = config.phase_out.reduction(status, agi);
+        capped.saturating_sub(reduction)
+    }
+
+    /// Calculates the allowed overtime-premium-pay deduction.
+    ///
+    /// Applies the per-return cap and the MAGI phase-out reduction; returns
+    /// zero if the rules don't define this deduction for the year.
+    fn calculate_overtime_deduction(
+        &self,
+        status: FilingStatus,
+        reported_overtime_premium: Money,
+        agi: Money,
+    ) -> Money {
+        let Some(config) = self.overtime_deduction() else {
+            return Money::ZERO;
+        };
+
+        let capped = reported_overtime_premium.min(config.cap);
+        let reduction = config.phase_out.reduction(status, agi);
+        capped.saturating_sub(reduction)
+    }
+
+    /// Sums the tip-income and overtime deductions into a single above-the-line
+    /// total (reported on Schedule 1-A and carried to Form 1040 line 13b).
+    fn other_above_the_line_deductions(
+        &self,
+        status: FilingStatus,
+        reported_tip_wages: Money,
+        reported_overtime_premium: Money,
+        agi: Money,
+        tip_occupation_eligible: bool,
+    ) -> Money {
+        self.calculate_tip_income_deduction(status, reported_tip_wages, agi, tip_occupation_eligible)
+            + self.calculate_overtime_deduction(status, reported_overtime_premium, agi)
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Student loan interest deduction (Schedule 1 Part II)
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Student loan interest deduction configuration (IRC § 221).
+    fn student_loan_interest_deduction(&self) -> Option<StudentLoanInterestDeduction> {
+        None
+    }
+
+    /// Calculates the allowed student loan interest deduction.
+    ///
+    /// Applies the per-return cap, then ratably phases the capped amount
+    /// out over the MAGI range (not the flat per-dollar `PhaseOut` model
+    /// used by the senior-bonus/tip/overtime deductions — § 221(b)(2)(B)
+    /// phases the deduction itself down to zero across a fixed dollar
+    /// range, proportional to how far into that range MAGI falls). Returns
+    /// zero if the rules don't define this deduction for the year. Callers
+    /// are responsible for passing `Money::ZERO` for married-filing-separately
+    /// taxpayers, who are categorically ineligible under § 221(e)(2).
+    fn calculate_student_loan_interest_deduction(
+        &self,
+        status: FilingStatus,
+        interest_paid: Money,
+        magi: Money,
+    ) -> Money {
+        let Some(config) = self.student_loan_interest_deduction() else {
+            return Money::ZERO;
+        };
+
+        let capped = interest_paid.min(config.cap);
+        config.phase_out.allowed_amount(status, magi, capped)
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Payroll tax (FICA)
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Social Security wage base (the wage cap above which the employee-share
+    /// Social Security tax no longer applies).
+    fn social_security_wage_base(&self) -> Money;
+
+    /// Social Security tax rate, employee share (e.g., 0.062 for 6.2%).
+    fn social_security_rate(&self) -> Decimal;
+
+    /// Medicare tax rate, employee share, uncapped (e.g., 0.0145 for 1.45%).
+    fn medicare_rate(&self) -> Decimal;
+
+    /// Additional Medicare Tax rate on wages above the filing-status threshold.
+    fn additional_medicare_rate(&self) -> Decimal {
+        Decimal::new(9, 3) // 0.009 (0.9%)
+    }
+
+    /// Additional Medicare Tax threshold for the given filing status.
+    ///
+    /// Unlike most FICA parameters these thresholds are not inflation-indexed.
+    fn additional_medicare_threshold(&self, status: FilingStatus) -> Money {
+        match status {
+            FilingStatus::MarriedFilingJointly => Money::from_dollars(250_000),
+            FilingStatus::MarriedFilingSeparately => Money::from_dollars(125_000),
+            FilingStatus::Single
+            | FilingStatus::HeadOfHousehold
+            | FilingStatus::QualifyingSurvivingSpouse => Money::from_dollars(200_000),
+        }
+    }
+
+    /// § 402(g) annual limit on elective deferrals to employer retirement
+    /// plans (Box 12 codes D/E/F/G/H/S/AA/BB/EE).
+    fn elective_deferral_limit(&self) -> Money;
+
+    /// Additional catch-up contribution allowance on top of
+    /// [`TaxRules::elective_deferral_limit`], for participants age 50+.
+    fn elective_deferral_catch_up_limit(&self) -> Money;
+
+    /// Tier 1 Railroad Retirement Tax Act (RRTA) wage base.
+    ///
+    /// By statute this mirrors the Social Security wage base.
+    fn rrta_tier_1_wage_base(&self) -> Money {
+        self.social_security_wage_base()
+    }
+
+    /// Tier 1 RRTA tax rate, employee share.
+    ///
+    /// By statute this mirrors the Social Security tax rate.
+    fn rrta_tier_1_rate(&self) -> Decimal {
+        self.social_security_rate()
+    }
+
+    /// Calculates payroll (FICA) tax on wages and self-employment income.
+    ///
+    /// Social Security tax applies only up to the wage base, with wages
+    /// consuming the base before self-employment income. Medicare tax is
+    /// uncapped. Self-employment earnings carry both the employer and
+    /// employee halves, so their rates are doubled relative to wages.
+    fn calculate_payroll_tax(
+        &self,
+        status: FilingStatus,
+        wages: Money,
+        self_employment_income: Money,
+    ) -> PayrollTax {
+        let wage_base = self.social_security_wage_base();
+        let ss_rate = self.social_security_rate();
+        let medicare_rate = self.medicare_rate();
+
+        let ss_taxable_wages = wages.min(wage_base);
+        let remaining_base = wage_base.saturating_sub(wages);
+        let ss_taxable_se = self_employment_income.min(remaining_base);
+
+        let social_security =
+            ss_taxable_wages.multiply_rate(ss_rate) + ss_taxable_se.multiply_rate(ss_rate * dec!(2));
+
+        let medicare = wages.multiply_rate(medicare_rate)
+            + self_employment_income.multiply_rate(medicare_rate * dec!(2));
+
+        let threshold = self.additional_medicare_threshold(status);
+        let total_earned = wages + self_employment_income;
+        let excess_over_threshold = total_earned.saturating_sub(threshold);
+        let additional_medicare = excess_over_threshold.multiply_rate(self.additional_medicare_rate());
+
+        PayrollTax {
+            social_security,
+            medicare,
+            additional_medicare,
+        }
+    }
+}
+
+/// Result of a payroll (FICA) tax calculation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PayrollTax {
+    /// Social Security tax (employee share on wages plus both halves on
+    /// self-employment income, capped at the wage base).
+    pub social_security: Money,
+    /// Medicare tax (employee share on wages plus both halves on
+    /// self-employment income, uncapped).
+    pub medicare: Money,
+    /// Additional Medicare Tax (0.9% above the filing-status threshold).
+    pub additional_medicare: Money,
+}
+
+impl PayrollTax {
+    /// Returns the total payroll tax across all components.
+    pub fn total(&self) -> Money {
+        self.social_security + self.medicare + self.additional_medicare
+    }
 }
 
 /// Senior bonus deduction configuration (OBBBA 2025-2028).
@@ -231,6 +483,97 @@ pub struct SeniorBonusDeduction {
     pub phase_out: PhaseOut,
 }
 
+/// Tip-income deduction configuration (OBBBA 2025-2028).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TipIncomeDeduction {
+    /// Maximum deductible amount per return.
+    pub cap: Money,
+    /// MAGI phase-out configuration.
+    pub phase_out: PhaseOut,
+    /// Whether the deduction is restricted to eligible tipped occupations.
+    pub occupation_eligibility_required: bool,
+}
+
+/// Overtime-premium-pay deduction configuration (OBBBA 2025-2028).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OvertimeDeduction {
+    /// Maximum deductible amount per return.
+    pub cap: Money,
+    /// MAGI phase-out configuration.
+    pub phase_out: PhaseOut,
+}
+
+/// Student loan interest deduction configuration (IRC § 221).
+///
+/// Married-filing-separately taxpayers are categorically ineligible per
+/// § 221(e)(2); that's enforced by the caller, not this configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudentLoanInterestDeduction {
+    /// Maximum deductible amount per return.
+    pub cap: Money,
+    /// MAGI phase-out configuration.
+    pub phase_out: RatablePhaseOut,
+}
+
+/// Ratable MAGI phase-out range (IRC § 221(b)(2)(B)).
+///
+/// Unlike [`PhaseOut`] (a flat per-dollar-of-excess reduction, used by the
+/// senior-bonus/tip/overtime deductions), this phases the deductible
+/// amount itself down to zero proportionally across a fixed dollar range:
+/// fully allowed below `threshold`, fully disallowed at `threshold + range`,
+/// and linearly in between. `range` is doubled for MFJ/QSS filers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RatablePhaseOut {
+    /// MAGI where the phase-out begins (single/HoH).
+    pub single_threshold: Money,
+    /// MAGI where the phase-out begins (MFJ/QSS).
+    pub joint_threshold: Money,
+    /// MAGI where the phase-out begins (MFS).
+    pub mfs_threshold: Money,
+    /// Dollar width of the phase-out range for single/HoH/MFS filers.
+    pub range: Money,
+}
+
+impl RatablePhaseOut {
+    /// Returns the threshold for the given filing status.
+    pub fn threshold_for(&self, status: FilingStatus) -> Money {
+        match status {
+            FilingStatus::Single | FilingStatus::HeadOfHousehold => self.single_threshold,
+            FilingStatus::MarriedFilingJointly | FilingStatus::QualifyingSurvivingSpouse => {
+                self.joint_threshold
+            }
+            FilingStatus::MarriedFilingSeparately => self.mfs_threshold,
+        }
+    }
+
+    /// Ratably reduces `amount` based on how far `magi` falls into the
+    /// phase-out range for `status`.
+    pub fn allowed_amount(&self, status: FilingStatus, magi: Money, amount: Money) -> Money {
+        let threshold = self.threshold_for(status);
+        if magi <= threshold {
+            return amount;
+        }
+
+        let range = match status {
+            FilingStatus::MarriedFilingJointly | FilingStatus::QualifyingSurvivingSpouse => {
+                self.range + self.range
+            }
+            _ => self.range,
+        };
+        if range.is_zero() {
+            return Money::ZERO;
+        }
+
+        let excess = magi - threshold;
+        if excess >= range {
+            return Money::ZERO;
+        }
+
+        let fraction_phased_out = excess.as_decimal() / range.as_decimal();
+        amount.saturating_sub(amount.multiply_rate(fraction_phased_out))
+    }
+}
+
 // Helper for multiplication
 impl std::ops::Mul<Money> for Money {
     type Output = Money;
@@ -122,6 +122,25 @@ impl Dependent {
         !self.qualifies_for_ctc()
     }
 
+    /// Names the first unmet [`Dependent::qualifies_for_ctc`] requirement,
+    /// for callers that want to explain a failed CTC qualification rather
+    /// than just report it. Checked in the same order as `qualifies_for_ctc`.
+    ///
+    /// Panics if the dependent actually qualifies for the CTC.
+    pub fn ctc_disqualification_reason(&self) -> &'static str {
+        if self.age >= 17 {
+            "age 17 or older"
+        } else if self.months_lived_with_taxpayer < 6 {
+            "lived with taxpayer fewer than 6 months"
+        } else if !self.is_qualifying_child_relationship() {
+            "relationship does not qualify as a qualifying child"
+        } else if self.ssn.is_empty() {
+            "missing SSN"
+        } else {
+            panic!("ctc_disqualification_reason called on a dependent that qualifies for the CTC")
+        }
+    }
+
     /// Returns true if relationship qualifies as a "qualifying child."
     fn is_qualifying_child_relationship(&self) -> bool {
         matches!(
@@ -1,11 +1,24 @@
 //! Currency type with IRS-compliant rounding rules.
 
+use crate::error::{TaxError, TaxResult};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::ops::{Add, AddAssign, Sub, SubAssign};
 
+/// Maximum whole-dollar magnitude a `UsdAmount` may hold, in either
+/// direction. No figure on a tax return plausibly exceeds this; treating it
+/// as a hard boundary (mirroring Zcash's `Amount::MAX_MONEY` pattern) turns
+/// a runaway calculation into an explicit `TaxError::Overflow` instead of a
+/// silently wrapped or out-of-range value.
+pub const MAX_DOLLARS: i64 = 999_999_999_999;
+
+/// [`MAX_DOLLARS`] expressed as a `Decimal`, for bounds checks against
+/// amounts that may carry cents. Must be kept numerically equal to
+/// [`MAX_DOLLARS`].
+const MAX_MAGNITUDE: Decimal = dec!(999_999_999_999);
+
 /// Represents a USD currency amount with cent precision.
 ///
 /// Internally uses `rust_decimal::Decimal` to avoid floating-point errors.
@@ -18,6 +31,69 @@ impl UsdAmount {
     /// Zero dollars.
     pub const ZERO: UsdAmount = UsdAmount(dec!(0));
 
+    /// Creates a whole-dollar amount at compile time, rejecting out-of-range
+    /// values as a compile error rather than a runtime one.
+    pub const fn const_from_dollars(dollars: i64) -> Self {
+        assert!(
+            dollars >= -MAX_DOLLARS && dollars <= MAX_DOLLARS,
+            "UsdAmount::const_from_dollars: magnitude exceeds MAX_DOLLARS"
+        );
+        Self(Decimal::from_i128_with_scale(dollars as i128, 0))
+    }
+
+    /// Creates a whole-dollar amount, rejecting magnitudes beyond
+    /// [`MAX_DOLLARS`] with `TaxError::Overflow` instead of panicking.
+    pub fn try_from_dollars(dollars: i64) -> TaxResult<Self> {
+        Self::try_new(Decimal::new(dollars, 0))
+    }
+
+    /// Creates an amount from a cent count, rejecting magnitudes beyond
+    /// [`MAX_DOLLARS`] with `TaxError::Overflow` instead of panicking.
+    pub fn try_from_cents(cents: i64) -> TaxResult<Self> {
+        Self::try_new(Decimal::new(cents, 2))
+    }
+
+    fn try_new(amount: Decimal) -> TaxResult<Self> {
+        if amount.abs() > MAX_MAGNITUDE {
+            Err(TaxError::Overflow(format!(
+                "amount {amount} exceeds the maximum representable magnitude of {MAX_DOLLARS}"
+            )))
+        } else {
+            Ok(Self(amount))
+        }
+    }
+
+    /// Checked addition: returns `TaxError::Overflow` if the underlying
+    /// `Decimal` addition overflows or the result leaves the valid range.
+    pub fn checked_add(&self, other: Self) -> TaxResult<Self> {
+        let sum = self
+            .0
+            .checked_add(other.0)
+            .ok_or_else(|| TaxError::Overflow(format!("{self} + {other} overflowed")))?;
+        Self::try_new(sum)
+    }
+
+    /// Checked subtraction: returns `TaxError::Overflow` if the underlying
+    /// `Decimal` subtraction overflows or the result leaves the valid range.
+    pub fn checked_sub(&self, other: Self) -> TaxResult<Self> {
+        let diff = self
+            .0
+            .checked_sub(other.0)
+            .ok_or_else(|| TaxError::Overflow(format!("{self} - {other} overflowed")))?;
+        Self::try_new(diff)
+    }
+
+    /// Checked rate multiplication: returns `TaxError::Overflow` if the
+    /// underlying `Decimal` multiplication overflows or the result leaves
+    /// the valid range.
+    pub fn checked_mul_rate(&self, rate: Decimal) -> TaxResult<Self> {
+        let product = self
+            .0
+            .checked_mul(rate)
+            .ok_or_else(|| TaxError::Overflow(format!("{self} * {rate} overflowed")))?;
+        Self::try_new(product)
+    }
+
     /// Create from a decimal value.
     pub fn new(amount: Decimal) -> Self {
         Self(amount)
@@ -105,7 +181,12 @@ impl UsdAmount {
 
     /// Multiply by a decimal rate (e.g., tax rate).
     pub fn multiply_rate(&self, rate: Decimal) -> Self {
-        Self(self.0 * rate)
+        let result = Self(self.0 * rate);
+        debug_assert!(
+            result.0.abs() <= MAX_MAGNITUDE,
+            "UsdAmount overflow: {self} * {rate} = {result}"
+        );
+        result
     }
 
     /// Saturating subtraction: returns zero if result would be negative.
@@ -134,13 +215,18 @@ impl Add for UsdAmount {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
-        Self(self.0 + other.0)
+        let result = Self(self.0 + other.0);
+        debug_assert!(
+            result.0.abs() <= MAX_MAGNITUDE,
+            "UsdAmount overflow: {self} + {other} = {result}"
+        );
+        result
     }
 }
 
 impl AddAssign for UsdAmount {
     fn add_assign(&mut self, other: Self) {
-        self.0 += other.0;
+        *self = *self + other;
     }
 }
 
@@ -148,13 +234,18 @@ impl Sub for UsdAmount {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
-        Self(self.0 - other.0)
+        let result = Self(self.0 - other.0);
+        debug_assert!(
+            result.0.abs() <= MAX_MAGNITUDE,
+            "UsdAmount overflow: {self} - {other} = {result}"
+        );
+        result
     }
 }
 
 impl SubAssign for UsdAmount {
     fn sub_assign(&mut self, other: Self) {
-        self.0 -= other.0;
+        *self = *self - other;
     }
 }
 
@@ -226,4 +317,61 @@ mod tests {
         let total: UsdAmount = amounts.into_iter().sum();
         assert_eq!(total, UsdAmount::from_dollars(600));
     }
+
+    #[test]
+    fn test_const_from_dollars() {
+        const AMOUNT: UsdAmount = UsdAmount::const_from_dollars(500);
+        assert_eq!(AMOUNT, UsdAmount::from_dollars(500));
+    }
+
+    #[test]
+    fn test_try_from_dollars_within_range() {
+        let amount = UsdAmount::try_from_dollars(100).unwrap();
+        assert_eq!(amount, UsdAmount::from_dollars(100));
+    }
+
+    #[test]
+    fn test_try_from_dollars_rejects_out_of_range() {
+        let result = UsdAmount::try_from_dollars(MAX_DOLLARS + 1);
+        assert!(matches!(result, Err(TaxError::Overflow(_))));
+    }
+
+    #[test]
+    fn test_checked_add_within_range() {
+        let a = UsdAmount::from_dollars(100);
+        let b = UsdAmount::from_dollars(200);
+        assert_eq!(a.checked_add(b).unwrap(), UsdAmount::from_dollars(300));
+    }
+
+    #[test]
+    fn test_checked_add_rejects_out_of_range() {
+        let a = UsdAmount::from_dollars(MAX_DOLLARS);
+        let b = UsdAmount::from_dollars(1);
+        assert!(matches!(a.checked_add(b), Err(TaxError::Overflow(_))));
+    }
+
+    #[test]
+    fn test_checked_sub_within_range() {
+        let a = UsdAmount::from_dollars(200);
+        let b = UsdAmount::from_dollars(50);
+        assert_eq!(a.checked_sub(b).unwrap(), UsdAmount::from_dollars(150));
+    }
+
+    #[test]
+    fn test_checked_mul_rate_within_range() {
+        let a = UsdAmount::from_dollars(1000);
+        assert_eq!(
+            a.checked_mul_rate(dec!(0.1)).unwrap(),
+            UsdAmount::from_dollars(100)
+        );
+    }
+
+    #[test]
+    fn test_checked_mul_rate_rejects_out_of_range() {
+        let a = UsdAmount::from_dollars(MAX_DOLLARS);
+        assert!(matches!(
+            a.checked_mul_rate(dec!(2)),
+            Err(TaxError::Overflow(_))
+        ));
+    }
 }
@@ -0,0 +1,129 @@
+//! Detects legacy integer-dollar money fields smuggled through a
+//! decimal-column import pipeline.
+//!
+//! Sources that historically stored money as whole-dollar integers
+//! sometimes migrate to decimal columns without backfilling the lost
+//! cents. When a field that's normally computed as a wage base times a
+//! statutory rate (withholding) comes through suspiciously whole-dollar
+//! while the wage base it was computed from carries cents, that's a strong
+//! signal the withholding figure was truncated upstream rather than
+//! genuinely being a round-dollar amount.
+
+use crate::error::ValidationErrors;
+use crate::money::Money;
+use crate::traits::InputForm;
+
+fn has_cents(amount: Money) -> bool {
+    !amount.as_decimal().fract().is_zero()
+}
+
+/// `(withheld_field_name, wage_base_field_name, withheld_accessor, wage_base_accessor)`.
+type CheckedPair = (
+    &'static str,
+    &'static str,
+    fn(&dyn InputForm) -> Option<Money>,
+    fn(&dyn InputForm) -> Option<Money>,
+);
+
+const CHECKED_PAIRS: &[CheckedPair] = &[
+    (
+        "social_security_tax_withheld",
+        "social_security_wages",
+        |f| f.social_security_tax_withheld(),
+        |f| f.social_security_wages(),
+    ),
+    (
+        "medicare_tax_withheld",
+        "medicare_wages",
+        |f| f.medicare_tax_withheld(),
+        |f| f.medicare_wages(),
+    ),
+    (
+        "federal_withholding",
+        "wages",
+        |f| f.federal_withholding(),
+        |f| f.wages(),
+    ),
+];
+
+impl ValidationErrors {
+    /// Flags money fields that are suspiciously whole-dollar given a
+    /// cross-related field on the same form that isn't, a signature of a
+    /// legacy integer-only column silently dropping cents on import.
+    pub fn check_cent_precision(form: &dyn InputForm) -> ValidationErrors {
+        let mut errors = ValidationErrors::new();
+        for &(withheld_name, base_name, withheld_fn, base_fn) in CHECKED_PAIRS {
+            let (Some(withheld), Some(base)) = (withheld_fn(form), base_fn(form)) else {
+                continue;
+            };
+            if !has_cents(withheld) && has_cents(base) {
+                errors.add_warning(
+                    withheld_name,
+                    format!(
+                        "{withheld_name} is a whole-dollar amount but {base_name} carries cents; \
+                         this may indicate cents were truncated by a legacy integer-column import"
+                    ),
+                );
+            }
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inputs::W2;
+    use crate::money::Money;
+
+    fn w2_with(
+        wages: Money,
+        federal_withholding: Money,
+        ss_wages: Money,
+        ss_withheld: Money,
+    ) -> W2 {
+        W2 {
+            box_1_wages: wages,
+            box_2_federal_tax_withheld: federal_withholding,
+            box_3_social_security_wages: ss_wages,
+            box_4_social_security_tax_withheld: ss_withheld,
+            ..W2::default()
+        }
+    }
+
+    #[test]
+    fn test_flags_whole_dollar_withholding_against_fractional_wages() {
+        let w2 = w2_with(
+            Money::from_cents(7_500_37),
+            Money::from_dollars(1_000),
+            Money::from_cents(7_500_37),
+            Money::from_dollars(465),
+        );
+        let errors = ValidationErrors::check_cent_precision(&w2);
+        assert!(errors.has_warnings());
+    }
+
+    #[test]
+    fn test_no_warning_when_withholding_also_carries_cents() {
+        let w2 = w2_with(
+            Money::from_cents(7_500_37),
+            Money::from_cents(1_000_12),
+            Money::from_cents(7_500_37),
+            Money::from_cents(465_02),
+        );
+        let errors = ValidationErrors::check_cent_precision(&w2);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_no_warning_when_wage_base_is_also_whole_dollar() {
+        let w2 = w2_with(
+            Money::from_dollars(75_000),
+            Money::from_dollars(10_000),
+            Money::from_dollars(75_000),
+            Money::from_dollars(4_650),
+        );
+        let errors = ValidationErrors::check_cent_precision(&w2);
+        assert!(errors.is_empty());
+    }
+}
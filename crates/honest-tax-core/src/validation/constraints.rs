@@ -0,0 +1,421 @@
+//! Constraint predicates evaluated over [`ReturnInputs`].
+
+use crate::error::ValidationError;
+use crate::money::Money;
+use crate::traits::{InputForm, InputFormCollection, TaxRules};
+use crate::types::{Dependent, FilingStatus, TaxpayerInfo};
+
+/// A structured constraint violation: field path, message, and severity.
+///
+/// Reuses [`ValidationError`]'s vocabulary so callers that already collect
+/// `ValidationError`s (e.g. form-level `validate()` methods) can merge
+/// constraint violations in without a second error type.
+pub type ConstraintViolation = ValidationError;
+
+/// The parsed state of a return that constraints are evaluated against.
+///
+/// Bundles the fields needed by cross-field invariants that no single
+/// `InputForm` or tax-math routine owns on its own.
+pub struct ReturnInputs<'a> {
+    /// Filing status claimed on the return.
+    pub filing_status: FilingStatus,
+    /// The primary taxpayer.
+    pub taxpayer: &'a TaxpayerInfo,
+    /// The spouse; only valid on a married-filing-jointly return (the
+    /// standard constraint set reports a spouse under any other filing
+    /// status as an error).
+    pub spouse: Option<&'a TaxpayerInfo>,
+    /// Claimed dependents.
+    pub dependents: &'a [Dependent],
+    /// All input forms collected for the return.
+    pub input_forms: &'a [Box<dyn InputForm>],
+    /// Adjusted gross income as computed so far.
+    pub agi: Money,
+    /// Whether the return claims the standard deduction.
+    pub claims_standard_deduction: bool,
+    /// Whether the return itemizes deductions (Schedule A).
+    pub itemizes: bool,
+}
+
+impl ReturnInputs<'_> {
+    /// Validates these inputs against the standard constraint set.
+    ///
+    /// Returns every violation at once rather than stopping at the first
+    /// one, so a caller can present a complete list of problems.
+    pub fn validate(&self, rules: &dyn TaxRules) -> Vec<ConstraintViolation> {
+        ConstraintSet::standard().validate(self, rules)
+    }
+}
+
+/// A side-effect-free predicate over [`ReturnInputs`].
+///
+/// Implementations must not perform tax math; they only check that the
+/// parsed inputs are internally consistent.
+pub trait Constraint: Send + Sync {
+    /// A short, stable name for this constraint (used in diagnostics/logs).
+    fn name(&self) -> &'static str;
+
+    /// Checks `inputs`, returning zero or more violations.
+    fn check(&self, inputs: &ReturnInputs, rules: &dyn TaxRules) -> Vec<ConstraintViolation>;
+}
+
+/// An ordered collection of constraints evaluated together.
+pub struct ConstraintSet {
+    constraints: Vec<Box<dyn Constraint>>,
+}
+
+impl ConstraintSet {
+    /// Creates an empty constraint set.
+    pub fn new() -> Self {
+        Self {
+            constraints: Vec::new(),
+        }
+    }
+
+    /// Returns the built-in constraint set covering the common return-level
+    /// invariants: dependent CTC age, standard-deduction/itemize exclusivity,
+    /// age/blindness flags, and AGI/wage bounds.
+    pub fn standard() -> Self {
+        let mut set = Self::new();
+        set.push(Box::new(DependentCtcAgeConstraint));
+        set.push(Box::new(StandardDeductionItemizeExclusivityConstraint));
+        set.push(Box::new(SpouseFlagsRequireJointConstraint));
+        set.push(Box::new(NonNegativeAmountsConstraint));
+        set
+    }
+
+    /// Adds a constraint to the set.
+    pub fn push(&mut self, constraint: Box<dyn Constraint>) {
+        self.constraints.push(constraint);
+    }
+
+    /// Evaluates every constraint in the set against `inputs`, returning all
+    /// violations from all constraints.
+    pub fn validate(
+        &self,
+        inputs: &ReturnInputs,
+        rules: &dyn TaxRules,
+    ) -> Vec<ConstraintViolation> {
+        self.constraints
+            .iter()
+            .flat_map(|c| c.check(inputs, rules))
+            .collect()
+    }
+}
+
+impl Default for ConstraintSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Warns when a claimed dependent does not qualify for the CTC, whether
+/// by age or by another requirement (residency, relationship, SSN), so the
+/// caller double-checks that the Credit for Other Dependents (not the CTC)
+/// applies instead.
+struct DependentCtcAgeConstraint;
+
+impl Constraint for DependentCtcAgeConstraint {
+    fn name(&self) -> &'static str {
+        "dependent_ctc_age"
+    }
+
+    fn check(&self, inputs: &ReturnInputs, _rules: &dyn TaxRules) -> Vec<ConstraintViolation> {
+        inputs
+            .dependents
+            .iter()
+            .enumerate()
+            .filter(|(_, dep)| dep.qualifies_for_odc())
+            .map(|(i, dep)| {
+                let message = if dep.age >= 17 {
+                    format!(
+                        "dependent is age {} (CTC requires under 17); verify the Credit for \
+                         Other Dependents is claimed instead of the Child Tax Credit",
+                        dep.age
+                    )
+                } else {
+                    // Under 17, so something other than age is why `qualifies_for_odc`
+                    // returned true — name it instead of blaming age.
+                    format!(
+                        "dependent does not qualify for the Child Tax Credit ({}); verify the \
+                         Credit for Other Dependents is claimed instead",
+                        dep.ctc_disqualification_reason()
+                    )
+                };
+                ConstraintViolation::warning(format!("dependents[{i}].age"), message)
+            })
+            .collect()
+    }
+}
+
+/// A return cannot claim the standard deduction and itemize at the same
+/// time.
+struct StandardDeductionItemizeExclusivityConstraint;
+
+impl Constraint for StandardDeductionItemizeExclusivityConstraint {
+    fn name(&self) -> &'static str {
+        "standard_deduction_itemize_exclusivity"
+    }
+
+    fn check(&self, inputs: &ReturnInputs, _rules: &dyn TaxRules) -> Vec<ConstraintViolation> {
+        if inputs.claims_standard_deduction && inputs.itemizes {
+            vec![ConstraintViolation::error(
+                "itemizes",
+                "a return cannot both claim the standard deduction and itemize deductions",
+            )]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+/// Spouse age/blindness flags are only meaningful when married filing
+/// jointly; there is no spouse to flag otherwise.
+struct SpouseFlagsRequireJointConstraint;
+
+impl Constraint for SpouseFlagsRequireJointConstraint {
+    fn name(&self) -> &'static str {
+        "spouse_flags_require_joint"
+    }
+
+    fn check(&self, inputs: &ReturnInputs, _rules: &dyn TaxRules) -> Vec<ConstraintViolation> {
+        if inputs.filing_status != FilingStatus::MarriedFilingJointly && inputs.spouse.is_some() {
+            vec![ConstraintViolation::error(
+                "spouse",
+                format!(
+                    "spouse information is only valid when filing status is married filing \
+                     jointly, got {}",
+                    inputs.filing_status
+                ),
+            )]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+/// Cross-field numeric bounds: AGI cannot be negative, and reported wages
+/// cannot be negative.
+struct NonNegativeAmountsConstraint;
+
+impl Constraint for NonNegativeAmountsConstraint {
+    fn name(&self) -> &'static str {
+        "non_negative_amounts"
+    }
+
+    fn check(&self, inputs: &ReturnInputs, _rules: &dyn TaxRules) -> Vec<ConstraintViolation> {
+        let mut violations = Vec::new();
+
+        if inputs.agi.is_negative() {
+            violations.push(ConstraintViolation::error(
+                "agi",
+                format!("AGI must not be negative, got {}", inputs.agi.as_decimal()),
+            ));
+        }
+
+        let total_wages = inputs.input_forms.total_wages();
+        if total_wages.is_negative() {
+            violations.push(ConstraintViolation::error(
+                "total_wages",
+                format!("total wages must not be negative, got {}", total_wages.as_decimal()),
+            ));
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ValidationSeverity;
+    use crate::rules::Rules2025;
+    use crate::traits::InputForm;
+    use crate::types::{InputFormType, TaxYear};
+
+    #[derive(Debug)]
+    struct StubForm {
+        wages: Money,
+    }
+
+    impl InputForm for StubForm {
+        fn form_type(&self) -> InputFormType {
+            InputFormType::W2
+        }
+
+        fn tax_year(&self) -> TaxYear {
+            2025
+        }
+
+        fn form_id(&self) -> &str {
+            "stub-1"
+        }
+
+        fn wages(&self) -> Option<Money> {
+            Some(self.wages)
+        }
+    }
+
+    fn taxpayer() -> TaxpayerInfo {
+        TaxpayerInfo {
+            first_name: "Jamie".to_string(),
+            last_name: "Rivera".to_string(),
+            ssn: "123-45-6789".to_string(),
+            date_of_birth: "1990-01-01".to_string(),
+            is_blind: false,
+        }
+    }
+
+    #[test]
+    fn test_standard_set_passes_clean_return() {
+        let taxpayer = taxpayer();
+        let forms: Vec<Box<dyn InputForm>> = vec![Box::new(StubForm {
+            wages: Money::from_dollars(60_000),
+        })];
+        let inputs = ReturnInputs {
+            filing_status: FilingStatus::Single,
+            taxpayer: &taxpayer,
+            spouse: None,
+            dependents: &[],
+            input_forms: &forms,
+            agi: Money::from_dollars(60_000),
+            claims_standard_deduction: true,
+            itemizes: false,
+        };
+
+        let rules = Rules2025::new();
+        assert!(inputs.validate(&rules).is_empty());
+    }
+
+    #[test]
+    fn test_standard_and_itemize_both_claimed_is_error() {
+        let taxpayer = taxpayer();
+        let forms: Vec<Box<dyn InputForm>> = Vec::new();
+        let inputs = ReturnInputs {
+            filing_status: FilingStatus::Single,
+            taxpayer: &taxpayer,
+            spouse: None,
+            dependents: &[],
+            input_forms: &forms,
+            agi: Money::from_dollars(60_000),
+            claims_standard_deduction: true,
+            itemizes: true,
+        };
+
+        let rules = Rules2025::new();
+        let violations = inputs.validate(&rules);
+        assert!(violations
+            .iter()
+            .any(|v| v.field == "itemizes" && v.severity == ValidationSeverity::Error));
+    }
+
+    #[test]
+    fn test_spouse_without_mfj_is_error() {
+        let taxpayer = taxpayer();
+        let spouse = taxpayer.clone();
+        let forms: Vec<Box<dyn InputForm>> = Vec::new();
+        let inputs = ReturnInputs {
+            filing_status: FilingStatus::Single,
+            taxpayer: &taxpayer,
+            spouse: Some(&spouse),
+            dependents: &[],
+            input_forms: &forms,
+            agi: Money::ZERO,
+            claims_standard_deduction: true,
+            itemizes: false,
+        };
+
+        let rules = Rules2025::new();
+        let violations = inputs.validate(&rules);
+        assert!(violations.iter().any(|v| v.field == "spouse"));
+    }
+
+    #[test]
+    fn test_negative_agi_is_error() {
+        let taxpayer = taxpayer();
+        let forms: Vec<Box<dyn InputForm>> = Vec::new();
+        let inputs = ReturnInputs {
+            filing_status: FilingStatus::Single,
+            taxpayer: &taxpayer,
+            spouse: None,
+            dependents: &[],
+            input_forms: &forms,
+            agi: Money::from_dollars(-100),
+            claims_standard_deduction: true,
+            itemizes: false,
+        };
+
+        let rules = Rules2025::new();
+        let violations = inputs.validate(&rules);
+        assert!(violations.iter().any(|v| v.field == "agi"));
+    }
+
+    #[test]
+    fn test_dependent_ctc_age_warning_names_age_when_17_or_older() {
+        let taxpayer = taxpayer();
+        let forms: Vec<Box<dyn InputForm>> = Vec::new();
+        let dependents = vec![Dependent {
+            first_name: "Alex".to_string(),
+            last_name: "Rivera".to_string(),
+            ssn: "987-65-4321".to_string(),
+            relationship: crate::types::DependentRelationship::Son,
+            age: 18,
+            months_lived_with_taxpayer: 12,
+            is_disabled: false,
+            is_student: false,
+        }];
+        let inputs = ReturnInputs {
+            filing_status: FilingStatus::Single,
+            taxpayer: &taxpayer,
+            spouse: None,
+            dependents: &dependents,
+            input_forms: &forms,
+            agi: Money::from_dollars(60_000),
+            claims_standard_deduction: true,
+            itemizes: false,
+        };
+
+        let rules = Rules2025::new();
+        let violations = inputs.validate(&rules);
+        let warning = violations
+            .iter()
+            .find(|v| v.field == "dependents[0].age")
+            .expect("expected a CTC age warning");
+        assert!(warning.message.contains("age 18"));
+    }
+
+    #[test]
+    fn test_dependent_ctc_age_warning_names_actual_reason_when_not_age() {
+        let taxpayer = taxpayer();
+        let forms: Vec<Box<dyn InputForm>> = Vec::new();
+        let dependents = vec![Dependent {
+            first_name: "Alex".to_string(),
+            last_name: "Rivera".to_string(),
+            ssn: String::new(),
+            relationship: crate::types::DependentRelationship::Son,
+            age: 10,
+            months_lived_with_taxpayer: 12,
+            is_disabled: false,
+            is_student: false,
+        }];
+        let inputs = ReturnInputs {
+            filing_status: FilingStatus::Single,
+            taxpayer: &taxpayer,
+            spouse: None,
+            dependents: &dependents,
+            input_forms: &forms,
+            agi: Money::from_dollars(60_000),
+            claims_standard_deduction: true,
+            itemizes: false,
+        };
+
+        let rules = Rules2025::new();
+        let violations = inputs.validate(&rules);
+        let warning = violations
+            .iter()
+            .find(|v| v.field == "dependents[0].age")
+            .expect("expected a CTC qualification warning");
+        assert!(!warning.message.contains("age 10"));
+        assert!(warning.message.contains("missing SSN"));
+    }
+}
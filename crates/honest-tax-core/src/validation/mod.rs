@@ -0,0 +1,21 @@
+//! Declarative constraint/validation layer over parsed return inputs.
+//!
+//! Constraints are side-effect-free predicates evaluated independently of
+//! the tax math, so a caller can surface every problem with a return before
+//! attempting to calculate it.
+//!
+//! This sits alongside two other validation mechanisms with narrower scope:
+//! [`crate::error::ValidationErrors::check_cent_precision`] (a single
+//! cross-field heuristic) and per-form-type methods like
+//! [`crate::inputs::W2::validation_errors`] (hand-written checks specific to
+//! one form). [`RuleSet`] exists for declarative, reusable checks shared
+//! across form *types* rather than hand-written per type; it deliberately
+//! doesn't re-run checks a form type already owns (see
+//! [`ValidationRule::social_security_wage_base_cap`]'s doc comment).
+
+mod cent_precision;
+mod constraints;
+mod rule_set;
+
+pub use constraints::{Constraint, ConstraintSet, ConstraintViolation, ReturnInputs};
+pub use rule_set::{RuleSet, ValidationRule};
@@ -0,0 +1,225 @@
+//! Declarative, reusable validation rules evaluated against a single
+//! [`InputForm`].
+//!
+//! Complements [`crate::validation::ConstraintSet`] (which checks a whole
+//! return's parsed inputs) with a lighter-weight engine for per-form checks:
+//! instead of scattering `errors.add_error(...)` calls through form-specific
+//! code, a [`RuleSet`] holds named, reusable [`ValidationRule`]s and runs
+//! them all against a form in one pass.
+
+use crate::error::{ValidationError, ValidationErrors, ValidationSeverity};
+use crate::money::Money;
+use crate::traits::{InputForm, TaxRules};
+
+/// A single named check evaluated against an [`InputForm`].
+///
+/// Returns `Ok(())` if the form passes, or the severity and message to
+/// record if it doesn't.
+pub struct ValidationRule {
+    /// Stable name used as the `ValidationError` field when this rule fails.
+    pub name: &'static str,
+    check: Box<dyn Fn(&dyn InputForm) -> Result<(), (ValidationSeverity, String)> + Send + Sync>,
+}
+
+impl ValidationRule {
+    /// Builds a rule from a name and a check closure.
+    pub fn new(
+        name: &'static str,
+        check: impl Fn(&dyn InputForm) -> Result<(), (ValidationSeverity, String)> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name,
+            check: Box::new(check),
+        }
+    }
+
+    /// Built-in: no income field on the form may be negative.
+    pub fn non_negative_income() -> Self {
+        Self::new("non_negative_income", |form| {
+            let fields: &[(&str, Option<Money>)] = &[
+                ("wages", form.wages()),
+                ("taxable_interest", form.taxable_interest()),
+                ("ordinary_dividends", form.ordinary_dividends()),
+                ("nonemployee_compensation", form.nonemployee_compensation()),
+                ("other_income", form.other_income()),
+            ];
+            for (name, value) in fields {
+                if let Some(amount) = value {
+                    if amount.is_negative() {
+                        return Err((
+                            ValidationSeverity::Error,
+                            format!("{name} must not be negative, got {}", amount.as_decimal()),
+                        ));
+                    }
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Built-in: Social Security wages must not exceed `cap` (the wage
+    /// base in effect for the form's tax year).
+    ///
+    /// Not included in [`RuleSet::standard`]: [`crate::inputs::W2`] already
+    /// runs an equivalent check (as a warning) in its own
+    /// `validation_errors`. This is available for non-W2 `InputForm`s that
+    /// don't have their own wage-base check.
+    pub fn social_security_wage_base_cap(cap: Money) -> Self {
+        Self::new("social_security_wage_base_cap", move |form| {
+            match form.social_security_wages() {
+                Some(wages) if wages > cap => Err((
+                    ValidationSeverity::Error,
+                    format!(
+                        "social_security_wages {} exceeds the wage base cap of {}",
+                        wages.as_decimal(),
+                        cap.as_decimal()
+                    ),
+                )),
+                _ => Ok(()),
+            }
+        })
+    }
+
+    /// Built-in: federal withholding should not exceed wages on the same
+    /// form (a sign of swapped or misreported boxes).
+    pub fn withholding_not_exceeding_wages() -> Self {
+        Self::new("withholding_not_exceeding_wages", |form| {
+            match (form.federal_withholding(), form.wages()) {
+                (Some(withheld), Some(wages)) if withheld > wages => Err((
+                    ValidationSeverity::Warning,
+                    format!(
+                        "federal_withholding {} exceeds wages {}",
+                        withheld.as_decimal(),
+                        wages.as_decimal()
+                    ),
+                )),
+                _ => Ok(()),
+            }
+        })
+    }
+}
+
+/// An ordered collection of [`ValidationRule`]s evaluated together.
+pub struct RuleSet {
+    rules: Vec<ValidationRule>,
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RuleSet {
+    /// Creates an empty rule set.
+    pub fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Adds a rule, returning `self` for chaining.
+    pub fn with_rule(mut self, rule: ValidationRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Pushes a rule onto an existing rule set.
+    pub fn push(&mut self, rule: ValidationRule) {
+        self.rules.push(rule);
+    }
+
+    /// Builds the standard rule set: non-negative income and
+    /// withholding-not-exceeding-wages.
+    ///
+    /// Doesn't include [`ValidationRule::social_security_wage_base_cap`]
+    /// even though `rules` is available to build it — see that
+    /// constructor's doc comment for why. `rules` is still taken here so
+    /// future built-ins that need statutory parameters can be added without
+    /// a signature change.
+    pub fn standard(_rules: &dyn TaxRules) -> Self {
+        Self::new()
+            .with_rule(ValidationRule::non_negative_income())
+            .with_rule(ValidationRule::withholding_not_exceeding_wages())
+    }
+
+    /// Runs every rule against `form`, funneling failures into a
+    /// `ValidationErrors`.
+    pub fn run(&self, form: &dyn InputForm) -> ValidationErrors {
+        let mut errors = ValidationErrors::new();
+        for rule in &self.rules {
+            if let Err((severity, message)) = (rule.check)(form) {
+                errors.add(match severity {
+                    ValidationSeverity::Error => ValidationError::error(rule.name, message),
+                    ValidationSeverity::Warning => ValidationError::warning(rule.name, message),
+                });
+            }
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inputs::W2;
+    use crate::rules::Rules2025;
+
+    fn w2_with(wages: Money, federal_withholding: Money, ss_wages: Money) -> W2 {
+        W2 {
+            box_1_wages: wages,
+            box_2_federal_tax_withheld: federal_withholding,
+            box_3_social_security_wages: ss_wages,
+            ..W2::default()
+        }
+    }
+
+    #[test]
+    fn test_standard_rule_set_passes_clean_w2() {
+        let tax_rules = Rules2025::new();
+        let rule_set = RuleSet::standard(&tax_rules);
+        let w2 = w2_with(
+            Money::from_dollars(75_000),
+            Money::from_dollars(10_000),
+            Money::from_dollars(75_000),
+        );
+        let errors = rule_set.run(&w2);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_non_negative_income_rule_flags_negative_wages() {
+        let w2 = w2_with(Money::from_dollars(-1), Money::ZERO, Money::ZERO);
+        let errors = RuleSet::new()
+            .with_rule(ValidationRule::non_negative_income())
+            .run(&w2);
+        assert!(errors.has_errors());
+    }
+
+    #[test]
+    fn test_ss_wage_base_cap_flags_excess_wages() {
+        let tax_rules = Rules2025::new();
+        let w2 = w2_with(
+            Money::from_dollars(200_000),
+            Money::ZERO,
+            Money::from_dollars(200_000),
+        );
+        let rule_set = RuleSet::new().with_rule(ValidationRule::social_security_wage_base_cap(
+            tax_rules.social_security_wage_base(),
+        ));
+        let errors = rule_set.run(&w2);
+        assert!(errors.has_errors());
+    }
+
+    #[test]
+    fn test_withholding_exceeding_wages_is_a_warning() {
+        let w2 = w2_with(
+            Money::from_dollars(10_000),
+            Money::from_dollars(20_000),
+            Money::from_dollars(10_000),
+        );
+        let errors = RuleSet::new()
+            .with_rule(ValidationRule::withholding_not_exceeding_wages())
+            .run(&w2);
+        assert!(errors.has_warnings());
+        assert!(!errors.has_errors());
+    }
+}